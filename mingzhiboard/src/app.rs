@@ -1,17 +1,27 @@
+use std::thread;
 use std::time::{Duration, Instant};
 
-use arboard::Clipboard;
+use arboard::{Clipboard, Error as ClipboardError, ImageData};
 use eframe::egui;
 
-use crate::core::{ClipboardHistory, ClipboardMode};
+use crate::core::{Accelerator, ClipboardHistory, ClipboardItem, ClipboardMode};
 use crate::platform::{PlatformEvent, PlatformHooks};
 
+// Stand-in for a real settings store: the default hotkey per platform, until
+// MingzhiBoard grows persisted user configuration.
+#[cfg(target_os = "macos")]
+const DEFAULT_ACCELERATOR: &str = "Cmd+V";
+#[cfg(target_os = "windows")]
+const DEFAULT_ACCELERATOR: &str = "Alt+Q";
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const DEFAULT_ACCELERATOR: &str = "Ctrl+Alt+V";
+
 pub struct MingzhiBoardApp {
     mode: ClipboardMode,
     history: ClipboardHistory,
     clipboard: Option<Clipboard>,
-    last_clipboard_text: Option<String>,
-    last_written_text: Option<String>,
+    last_clipboard_item: Option<ClipboardItem>,
+    last_written_item: Option<ClipboardItem>,
     suppress_next_record: bool,
     last_paste_preview: Option<String>,
     last_poll: Instant,
@@ -28,42 +38,56 @@ impl MingzhiBoardApp {
             mode: ClipboardMode::Stack,
             history: ClipboardHistory::new(200),
             clipboard,
-            last_clipboard_text: None,
-            last_written_text: None,
+            last_clipboard_item: None,
+            last_written_item: None,
             suppress_next_record: false,
             last_paste_preview: None,
             last_poll: Instant::now(),
             poll_interval: Duration::from_millis(500),
             clipboard_error: None,
-            platform: PlatformHooks::new(),
+            platform: PlatformHooks::new(
+                DEFAULT_ACCELERATOR
+                    .parse::<Accelerator>()
+                    .expect("built-in default accelerator must parse"),
+            ),
         }
     }
 
+    /// Fallback path for platforms/registrations where the OS won't push
+    /// clipboard-change notifications; skipped entirely once
+    /// `PlatformStatus::clipboard_events` is active, since `record_clipboard_change`
+    /// is driven by `handle_platform_events` instead.
     fn poll_clipboard(&mut self) {
+        if self.platform.status.clipboard_events {
+            return;
+        }
         if self.last_poll.elapsed() < self.poll_interval {
             return;
         }
         self.last_poll = Instant::now();
+        self.record_clipboard_change();
+    }
 
+    fn record_clipboard_change(&mut self) {
         let Some(clipboard) = self.clipboard.as_mut() else {
             self.clipboard_error = Some("Clipboard unavailable".to_string());
             return;
         };
 
-        match clipboard.get_text() {
-            Ok(text) => {
+        match read_clipboard_item(clipboard) {
+            Ok(item) => {
                 if self.suppress_next_record {
-                    if self.last_written_text.as_deref() == Some(text.as_str()) {
-                        self.last_clipboard_text = Some(text);
+                    if self.last_written_item.as_ref() == Some(&item) {
+                        self.last_clipboard_item = Some(item);
                         self.clipboard_error = None;
                         self.suppress_next_record = false;
                         return;
                     }
                     self.suppress_next_record = false;
                 }
-                if self.last_clipboard_text.as_deref() != Some(text.as_str()) {
-                    self.history.record_copy(&text, self.mode);
-                    self.last_clipboard_text = Some(text);
+                if self.last_clipboard_item.as_ref() != Some(&item) {
+                    self.history.record_copy(item.clone(), self.mode);
+                    self.last_clipboard_item = Some(item);
                     self.clipboard_error = None;
                 }
             }
@@ -77,12 +101,13 @@ impl MingzhiBoardApp {
         while let Some(event) = self.platform.next_event() {
             match event {
                 PlatformEvent::PasteRequested => self.handle_paste_request(),
+                PlatformEvent::ClipboardChanged => self.record_clipboard_change(),
             }
         }
     }
 
     fn handle_paste_request(&mut self) {
-        let Some(text) = self.history.next_paste(self.mode) else {
+        let Some(item) = self.history.next_paste(self.mode) else {
             self.last_paste_preview = None;
             return;
         };
@@ -92,15 +117,15 @@ impl MingzhiBoardApp {
             return;
         };
 
-        if let Err(err) = clipboard.set_text(text.clone()) {
+        if let Err(err) = write_clipboard_item(clipboard, &item) {
             self.clipboard_error = Some(format!("Clipboard write error: {err}"));
             return;
         }
 
-        self.last_clipboard_text = Some(text.clone());
-        self.last_written_text = Some(text.clone());
+        self.last_paste_preview = Some(preview_for_item(&item));
+        self.last_clipboard_item = Some(item.clone());
+        self.last_written_item = Some(item);
         self.suppress_next_record = true;
-        self.last_paste_preview = Some(text);
         self.clipboard_error = None;
 
         if let Err(err) = crate::platform::simulate_paste() {
@@ -109,6 +134,94 @@ impl MingzhiBoardApp {
     }
 }
 
+const CLIPBOARD_RETRY_ATTEMPTS: u8 = 10;
+const CLIPBOARD_RETRY_DELAY: Duration = Duration::from_millis(15);
+
+/// Retries a fallible clipboard operation a few times with a short delay.
+/// Apps like Excel hold the Windows clipboard open for a few milliseconds at
+/// a time, so a single `arboard` read or write intermittently fails even
+/// though the clipboard is perfectly healthy a moment later.
+fn with_retry<T>(max: u8, mut f: impl FnMut() -> Result<T, String>) -> Result<T, String> {
+    let attempts = max.max(1);
+    let mut last_err = String::new();
+    for attempt in 0..attempts {
+        match f() {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                last_err = err;
+                if attempt + 1 < attempts {
+                    thread::sleep(CLIPBOARD_RETRY_DELAY);
+                }
+            }
+        }
+    }
+    Err(last_err)
+}
+
+/// Reads whichever clipboard format is present, preferring an image since a
+/// pasted picture rarely also carries plain text worth keeping.
+///
+/// Both probes are checked once, untouched by retry, before falling back to
+/// the retry loop: copying plain text makes `get_image` fail with
+/// `ContentNotAvailable` every single time, and copying something that's
+/// neither text nor image (e.g. a file selected in a file manager) makes
+/// `get_text` fail the same way. Sleep-retrying either case would stall the
+/// UI thread for no benefit. Only a genuine transient failure (the
+/// clipboard actually locked by another app) gets the retry treatment.
+fn read_clipboard_item(clipboard: &mut Clipboard) -> Result<ClipboardItem, String> {
+    let image_result = match clipboard.get_image() {
+        Ok(image) => Ok(image),
+        Err(ClipboardError::ContentNotAvailable) => Err(ClipboardError::ContentNotAvailable.to_string()),
+        Err(_) => with_retry(CLIPBOARD_RETRY_ATTEMPTS, || {
+            clipboard.get_image().map_err(|err| err.to_string())
+        }),
+    };
+
+    match image_result {
+        Ok(image) => Ok(ClipboardItem::Image {
+            width: image.width,
+            height: image.height,
+            bytes: image.bytes.into_owned(),
+        }),
+        Err(_) => match clipboard.get_text() {
+            Ok(text) => Ok(text),
+            Err(ClipboardError::ContentNotAvailable) => Err(ClipboardError::ContentNotAvailable.to_string()),
+            Err(_) => with_retry(CLIPBOARD_RETRY_ATTEMPTS, || {
+                clipboard.get_text().map_err(|err| err.to_string())
+            }),
+        }
+        .map(ClipboardItem::Text),
+    }
+}
+
+fn write_clipboard_item(clipboard: &mut Clipboard, item: &ClipboardItem) -> Result<(), String> {
+    match item {
+        ClipboardItem::Text(text) => with_retry(CLIPBOARD_RETRY_ATTEMPTS, || {
+            clipboard.set_text(text.clone()).map_err(|err| err.to_string())
+        }),
+        ClipboardItem::Image {
+            width,
+            height,
+            bytes,
+        } => with_retry(CLIPBOARD_RETRY_ATTEMPTS, || {
+            clipboard
+                .set_image(ImageData {
+                    width: *width,
+                    height: *height,
+                    bytes: bytes.clone().into(),
+                })
+                .map_err(|err| err.to_string())
+        }),
+    }
+}
+
+fn preview_for_item(item: &ClipboardItem) -> String {
+    match item {
+        ClipboardItem::Text(text) => text.clone(),
+        ClipboardItem::Image { width, height, .. } => format!("<image {width}x{height}>"),
+    }
+}
+
 impl eframe::App for MingzhiBoardApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         self.poll_clipboard();
@@ -137,17 +250,30 @@ impl eframe::App for MingzhiBoardApp {
                 "Hotkeys: {}",
                 self.platform.status.hotkeys
             ));
-            ui.label(format!(
-                "Polling: {} ms",
-                self.poll_interval.as_millis()
-            ));
+            ui.label(if self.platform.status.clipboard_events {
+                "Clipboard capture: event-driven".to_string()
+            } else {
+                format!(
+                    "Clipboard capture: polling every {} ms",
+                    self.poll_interval.as_millis()
+                )
+            });
+
+            let dropped = self.platform.status.dropped_events();
+            if dropped > 0 {
+                ui.colored_label(
+                    egui::Color32::YELLOW,
+                    format!("{dropped} hotkeys dropped (event queue was full)"),
+                );
+            }
 
             ui.separator();
             ui.label(format!(
                 "Last clipboard item: {}",
-                self.last_clipboard_text
-                    .as_deref()
-                    .unwrap_or("<empty>")
+                self.last_clipboard_item
+                    .as_ref()
+                    .map(preview_for_item)
+                    .unwrap_or_else(|| "<empty>".to_string())
             ));
 
             ui.label(format!(
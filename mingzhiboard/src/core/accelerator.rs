@@ -0,0 +1,191 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A bitset of keyboard modifiers. `SUPER` covers Cmd on macOS and the
+/// Windows/Super key elsewhere, mirroring how other cross-platform hotkey
+/// libraries collapse the two.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ModifierFlags(u8);
+
+impl ModifierFlags {
+    pub const NONE: Self = Self(0);
+    pub const CONTROL: Self = Self(1 << 0);
+    pub const ALT: Self = Self(1 << 1);
+    pub const SHIFT: Self = Self(1 << 2);
+    pub const SUPER: Self = Self(1 << 3);
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl std::ops::BitOr for ModifierFlags {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for ModifierFlags {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// The non-modifier half of an accelerator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyCode {
+    Letter(char),
+    Digit(u8),
+    Function(u8),
+    Punctuation(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Accelerator {
+    pub mods: ModifierFlags,
+    pub key: KeyCode,
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.mods.contains(ModifierFlags::CONTROL) {
+            write!(f, "Ctrl+")?;
+        }
+        if self.mods.contains(ModifierFlags::ALT) {
+            write!(f, "Alt+")?;
+        }
+        if self.mods.contains(ModifierFlags::SHIFT) {
+            write!(f, "Shift+")?;
+        }
+        if self.mods.contains(ModifierFlags::SUPER) {
+            write!(f, "Cmd+")?;
+        }
+        match self.key {
+            KeyCode::Letter(ch) => write!(f, "{ch}"),
+            KeyCode::Digit(n) => write!(f, "{n}"),
+            KeyCode::Function(n) => write!(f, "F{n}"),
+            KeyCode::Punctuation(ch) => write!(f, "{ch}"),
+        }
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens: Vec<&str> = s.split('+').map(str::trim).filter(|t| !t.is_empty()).collect();
+        let Some((key_token, mod_tokens)) = tokens.split_last() else {
+            return Err(format!("empty accelerator string: \"{s}\""));
+        };
+
+        let mut mods = ModifierFlags::NONE;
+        for token in mod_tokens {
+            mods |= parse_modifier(token)?;
+        }
+
+        let key = parse_key(key_token)?;
+        Ok(Accelerator { mods, key })
+    }
+}
+
+fn parse_modifier(token: &str) -> Result<ModifierFlags, String> {
+    match token.to_ascii_lowercase().as_str() {
+        "ctrl" | "control" => Ok(ModifierFlags::CONTROL),
+        "alt" | "option" => Ok(ModifierFlags::ALT),
+        "shift" => Ok(ModifierFlags::SHIFT),
+        "cmd" | "command" | "super" | "win" | "meta" => Ok(ModifierFlags::SUPER),
+        other => Err(format!("unknown modifier \"{other}\" in accelerator")),
+    }
+}
+
+fn parse_key(token: &str) -> Result<KeyCode, String> {
+    if let Some(digits) = token.strip_prefix('F').or_else(|| token.strip_prefix('f')) {
+        // Only a digit suffix makes this a function key; otherwise it's the
+        // letter F/f itself (e.g. "Ctrl+F"), which falls through below.
+        if !digits.is_empty() {
+            if let Ok(n) = digits.parse::<u8>() {
+                if (1..=24).contains(&n) {
+                    return Ok(KeyCode::Function(n));
+                }
+            }
+            return Err(format!("unknown function key \"{token}\" in accelerator (expected F1-F24)"));
+        }
+    }
+
+    let mut chars = token.chars();
+    let (Some(ch), None) = (chars.next(), chars.next()) else {
+        return Err(format!("unknown key \"{token}\" in accelerator (expected a single character or F1-F24)"));
+    };
+
+    if ch.is_ascii_alphabetic() {
+        Ok(KeyCode::Letter(ch.to_ascii_uppercase()))
+    } else if ch.is_ascii_digit() {
+        Ok(KeyCode::Digit(ch as u8 - b'0'))
+    } else if ch.is_ascii_punctuation() {
+        Ok(KeyCode::Punctuation(ch))
+    } else {
+        Err(format!("unknown key \"{token}\" in accelerator"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_simple_combo() {
+        let accel: Accelerator = "Alt+Q".parse().unwrap();
+        assert_eq!(accel.mods, ModifierFlags::ALT);
+        assert_eq!(accel.key, KeyCode::Letter('Q'));
+    }
+
+    #[test]
+    fn parses_multiple_modifiers() {
+        let accel: Accelerator = "Ctrl+Shift+V".parse().unwrap();
+        assert!(accel.mods.contains(ModifierFlags::CONTROL));
+        assert!(accel.mods.contains(ModifierFlags::SHIFT));
+        assert_eq!(accel.key, KeyCode::Letter('V'));
+    }
+
+    #[test]
+    fn parses_super_aliases() {
+        let accel: Accelerator = "Cmd+Option+1".parse().unwrap();
+        assert!(accel.mods.contains(ModifierFlags::SUPER));
+        assert!(accel.mods.contains(ModifierFlags::ALT));
+        assert_eq!(accel.key, KeyCode::Digit(1));
+    }
+
+    #[test]
+    fn parses_function_keys() {
+        let accel: Accelerator = "Ctrl+F13".parse().unwrap();
+        assert_eq!(accel.key, KeyCode::Function(13));
+    }
+
+    #[test]
+    fn rejects_unknown_modifier() {
+        assert!("Fn+Q".parse::<Accelerator>().is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_function_key() {
+        assert!("F25".parse::<Accelerator>().is_err());
+    }
+
+    #[test]
+    fn parses_letter_f_key() {
+        let accel: Accelerator = "Ctrl+F".parse().unwrap();
+        assert_eq!(accel.key, KeyCode::Letter('F'));
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let accel: Accelerator = "Ctrl+Alt+V".parse().unwrap();
+        assert_eq!(accel.to_string(), "Ctrl+Alt+V");
+    }
+}
@@ -2,10 +2,32 @@ use std::collections::VecDeque;
 
 use super::ClipboardMode;
 
+/// A single clipboard entry. Plain text is the common case, but a copy can
+/// also be an image, the only other format `arboard` exposes a portable
+/// read/write API for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ClipboardItem {
+    Text(String),
+    Image {
+        width: usize,
+        height: usize,
+        bytes: Vec<u8>,
+    },
+}
+
+impl ClipboardItem {
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            ClipboardItem::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
 pub struct ClipboardHistory {
-    stack: Vec<String>,
-    queue: VecDeque<String>,
-    increment_text: Option<String>,
+    stack: Vec<ClipboardItem>,
+    queue: VecDeque<ClipboardItem>,
+    increment_item: Option<ClipboardItem>,
     max_items: usize,
 }
 
@@ -14,35 +36,40 @@ impl ClipboardHistory {
         Self {
             stack: Vec::new(),
             queue: VecDeque::new(),
-            increment_text: None,
+            increment_item: None,
             max_items: max_items.max(1),
         }
     }
 
-    pub fn record_copy(&mut self, text: &str, mode: ClipboardMode) {
+    pub fn record_copy(&mut self, item: ClipboardItem, mode: ClipboardMode) {
         match mode {
             ClipboardMode::Stack => {
-                self.stack.push(text.to_string());
+                self.stack.push(item);
                 self.trim_stack();
             }
             ClipboardMode::Queue => {
-                self.queue.push_back(text.to_string());
+                self.queue.push_back(item);
                 self.trim_queue();
             }
             ClipboardMode::Increment => {
-                self.increment_text = Some(text.to_string());
+                self.increment_item = Some(item);
             }
         }
     }
 
-    pub fn next_paste(&mut self, mode: ClipboardMode) -> Option<String> {
+    pub fn next_paste(&mut self, mode: ClipboardMode) -> Option<ClipboardItem> {
         match mode {
             ClipboardMode::Stack => self.stack.pop(),
             ClipboardMode::Queue => self.queue.pop_front(),
             ClipboardMode::Increment => {
-                let current = self.increment_text.as_deref()?;
-                let next = increment_numbers(current);
-                self.increment_text = Some(next.clone());
+                let current = self.increment_item.as_ref()?;
+                let next = match current {
+                    ClipboardItem::Text(text) => ClipboardItem::Text(increment_numbers(text)),
+                    // Images have nothing to increment, so they pass through
+                    // unchanged on every paste.
+                    other => other.clone(),
+                };
+                self.increment_item = Some(next.clone());
                 Some(next)
             }
         }
@@ -57,7 +84,7 @@ impl ClipboardHistory {
     }
 
     pub fn increment_text(&self) -> Option<&str> {
-        self.increment_text.as_deref()
+        self.increment_item.as_ref().and_then(ClipboardItem::as_text)
     }
 
     fn trim_stack(&mut self) {
@@ -0,0 +1,7 @@
+pub mod accelerator;
+mod clipboard;
+mod modes;
+
+pub use accelerator::Accelerator;
+pub use clipboard::{ClipboardHistory, ClipboardItem};
+pub use modes::ClipboardMode;
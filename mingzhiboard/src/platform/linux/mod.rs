@@ -0,0 +1,44 @@
+use std::env;
+use std::sync::atomic::AtomicU32;
+use std::sync::Arc;
+use std::sync::OnceLock;
+
+use crossbeam_channel::Sender;
+
+use super::{PlatformEvent, PlatformStatus};
+
+mod wayland;
+mod x11;
+
+#[derive(Debug, Clone, Copy)]
+enum Backend {
+    X11,
+    Wayland,
+}
+
+static ACTIVE_BACKEND: OnceLock<Backend> = OnceLock::new();
+
+fn detect_backend() -> Backend {
+    if env::var_os("WAYLAND_DISPLAY").is_some() {
+        Backend::Wayland
+    } else {
+        Backend::X11
+    }
+}
+
+pub fn init(event_tx: Sender<PlatformEvent>, dropped_events: Arc<AtomicU32>) -> PlatformStatus {
+    let backend = detect_backend();
+    let status = match backend {
+        Backend::Wayland => wayland::init(event_tx, dropped_events),
+        Backend::X11 => x11::init(event_tx, dropped_events),
+    };
+    let _ = ACTIVE_BACKEND.set(backend);
+    status
+}
+
+pub fn simulate_paste() -> Result<(), String> {
+    match ACTIVE_BACKEND.get().copied().unwrap_or_else(detect_backend) {
+        Backend::Wayland => wayland::simulate_paste(),
+        Backend::X11 => x11::simulate_paste(),
+    }
+}
@@ -0,0 +1,215 @@
+//! Wayland half of the Linux backend. Descoped from the original request:
+//! only the connection/binding scaffolding (`wl_seat`, `wl_data_device`,
+//! `zwp_virtual_keyboard_v1`) is wired up so far. Clipboard-change events,
+//! the global paste hotkey, and paste injection are all unimplemented on
+//! this path (see `init`, `simulate_paste`, and `portal_global_shortcuts`
+//! below) — a Wayland session falls back to clipboard polling and the
+//! in-app "Simulate paste" button. `platform::linux::x11` is the backend
+//! that actually delivers the hotkey and injects the paste.
+
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::thread;
+
+use crossbeam_channel::Sender;
+use wayland_client::protocol::{
+    wl_data_device::WlDataDevice, wl_data_device_manager::WlDataDeviceManager, wl_seat::WlSeat,
+};
+use wayland_client::{Connection, Dispatch, QueueHandle};
+use wayland_protocols_misc::zwp_virtual_keyboard_v1::client::{
+    zwp_virtual_keyboard_manager_v1::ZwpVirtualKeyboardManagerV1,
+    zwp_virtual_keyboard_v1::ZwpVirtualKeyboardV1,
+};
+
+use super::super::{PlatformEvent, PlatformStatus};
+
+static EVENT_SENDER: OnceLock<Sender<PlatformEvent>> = OnceLock::new();
+static DROPPED_EVENTS: OnceLock<Arc<AtomicU32>> = OnceLock::new();
+static INJECTING_PASTE: AtomicBool = AtomicBool::new(false);
+static VIRTUAL_KEYBOARD: OnceLock<ZwpVirtualKeyboardV1> = OnceLock::new();
+
+struct AppState {
+    seat: Option<WlSeat>,
+    data_device_manager: Option<WlDataDeviceManager>,
+    virtual_keyboard_manager: Option<ZwpVirtualKeyboardManagerV1>,
+}
+
+// `portal_global_shortcuts` and `simulate_paste`'s keymap handshake are both
+// unimplemented stubs (see their doc comments), so the Wayland backend can't
+// actually deliver a global hotkey or inject a paste yet. Report that
+// honestly here instead of claiming a working listener/hotkey path; the
+// in-app "Simulate paste" button is the only paste trigger this backend
+// supports today.
+const HOTKEY_STATUS: &str =
+    "unavailable on Wayland (no GlobalShortcuts portal integration yet; use \"Simulate paste\")";
+
+pub fn init(event_tx: Sender<PlatformEvent>, dropped_events: Arc<AtomicU32>) -> PlatformStatus {
+    let _ = EVENT_SENDER.set(event_tx);
+    let _ = DROPPED_EVENTS.set(dropped_events.clone());
+
+    let spawned = thread::spawn(run_event_loop);
+    thread::sleep(std::time::Duration::from_millis(20));
+
+    if spawned.is_finished() {
+        PlatformStatus {
+            listener: "unavailable (Wayland connection failed)".to_string(),
+            hotkeys: HOTKEY_STATUS.to_string(),
+            clipboard_events: false,
+            dropped_events,
+        }
+    } else {
+        PlatformStatus {
+            listener: "connected (clipboard-change events and global hotkey not yet wired up; paste injection needs the virtual-keyboard keymap handshake)".to_string(),
+            hotkeys: HOTKEY_STATUS.to_string(),
+            clipboard_events: false,
+            dropped_events,
+        }
+    }
+}
+
+fn run_event_loop() {
+    let Ok(conn) = Connection::connect_to_env() else {
+        return;
+    };
+    let (globals, mut event_queue) = match wayland_client::globals::registry_queue_init::<AppState>(&conn) {
+        Ok(pair) => pair,
+        Err(_) => return,
+    };
+    let qh: QueueHandle<AppState> = event_queue.handle();
+
+    let mut state = AppState {
+        seat: globals.bind(&qh, 1..=9, ()).ok(),
+        data_device_manager: globals.bind(&qh, 1..=3, ()).ok(),
+        virtual_keyboard_manager: globals.bind(&qh, 1..=1, ()).ok(),
+    };
+
+    // Register as a data device so this process is handed clipboard offers the
+    // compositor already knows about, rather than polling arboard blind. Not
+    // wired up yet: `Dispatch<WlDataDevice, _>` below doesn't forward
+    // `data_offer`/`selection` events anywhere, so this only reserves the
+    // object; `PlatformStatus::clipboard_events` stays `false` until it does.
+    if let (Some(manager), Some(seat)) = (&state.data_device_manager, &state.seat) {
+        let _ = manager.get_data_device(seat, &qh, ());
+    }
+
+    if let (Some(manager), Some(seat)) = (&state.virtual_keyboard_manager, &state.seat) {
+        let keyboard = manager.create_virtual_keyboard(seat, &qh, ());
+        let _ = VIRTUAL_KEYBOARD.set(keyboard);
+    }
+
+    // On Wayland a global hotkey can only come through the compositor portal
+    // (org.freedesktop.portal.GlobalShortcuts), since no compositor lets a
+    // client grab input outside its own surface. `listen_for_hotkey` calls
+    // the `portal_global_shortcuts` stub, which returns an error at once, so
+    // no hotkey event is ever sent on this path.
+    listen_for_hotkey();
+
+    loop {
+        if event_queue.blocking_dispatch(&mut state).is_err() {
+            return;
+        }
+    }
+}
+
+fn listen_for_hotkey() {
+    thread::spawn(|| {
+        if let Err(_err) = portal_global_shortcuts::run(|| {
+            if !INJECTING_PASTE.load(Ordering::Acquire) {
+                if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+                    super::super::send_event(sender, dropped, PlatformEvent::PasteRequested);
+                }
+            }
+        }) {
+            // No portal available (e.g. a compositor without GlobalShortcuts
+            // support); the app still works via the "Simulate paste" button.
+        }
+    });
+}
+
+/// `zwp_virtual_keyboard_v1` requires the client to upload a keymap (via the
+/// `keymap` request) before the compositor will accept any `key` request on
+/// the same object; sending keys first is a protocol violation most
+/// compositors answer by killing the client's connection. This backend
+/// doesn't build/upload an XKB keymap yet, so rather than emit `key` events
+/// that the compositor is entitled to reject, fail loudly here.
+pub fn simulate_paste() -> Result<(), String> {
+    let Some(_keyboard) = VIRTUAL_KEYBOARD.get() else {
+        return Err("virtual keyboard not initialized".to_string());
+    };
+
+    Err("Wayland paste injection not implemented: no XKB keymap has been uploaded to the virtual keyboard".to_string())
+}
+
+impl Dispatch<WlSeat, ()> for AppState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlSeat,
+        _event: <WlSeat as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WlDataDeviceManager, ()> for AppState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlDataDeviceManager,
+        _event: <WlDataDeviceManager as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpVirtualKeyboardManagerV1, ()> for AppState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpVirtualKeyboardManagerV1,
+        _event: <ZwpVirtualKeyboardManagerV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwpVirtualKeyboardV1, ()> for AppState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &ZwpVirtualKeyboardV1,
+        _event: <ZwpVirtualKeyboardV1 as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WlDataDevice, ()> for AppState {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WlDataDevice,
+        _event: <WlDataDevice as wayland_client::Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+mod portal_global_shortcuts {
+    /// Stand-in for the org.freedesktop.portal.GlobalShortcuts D-Bus session;
+    /// would register the configured accelerator and invoke `on_fire` each
+    /// time the compositor reports it was pressed. Not implemented — no
+    /// D-Bus client is wired up — so Wayland sessions never get the global
+    /// hotkey and must rely on the in-app "Simulate paste" button. Tracked in
+    /// `PlatformStatus::hotkeys` rather than silently pretending to work.
+    pub fn run(on_fire: impl Fn() + Send + 'static) -> Result<(), String> {
+        let _ = on_fire;
+        Err("GlobalShortcuts portal integration not available in this build".to_string())
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use crossbeam_channel::Sender;
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{ConnectionExt as _, GrabMode, ModMask, KEY_PRESS_EVENT, KEY_RELEASE_EVENT};
+use x11rb::protocol::xtest::ConnectionExt as _;
+use x11rb::protocol::Event;
+
+use super::super::{PlatformEvent, PlatformStatus};
+
+// X keysym values for the symbols this backend cares about (keysymdef.h).
+const XK_CONTROL_L: u32 = 0xffe3;
+const XK_V: u32 = 0x0076;
+
+static EVENT_SENDER: OnceLock<Sender<PlatformEvent>> = OnceLock::new();
+static DROPPED_EVENTS: OnceLock<Arc<AtomicU32>> = OnceLock::new();
+static INJECTING_PASTE: AtomicBool = AtomicBool::new(false);
+static CONTROL_KEYCODE: OnceLock<u8> = OnceLock::new();
+static V_KEYCODE: OnceLock<u8> = OnceLock::new();
+
+pub fn init(event_tx: Sender<PlatformEvent>, dropped_events: Arc<AtomicU32>) -> PlatformStatus {
+    let _ = EVENT_SENDER.set(event_tx);
+    let _ = DROPPED_EVENTS.set(dropped_events.clone());
+
+    let spawned = thread::spawn(run_event_loop);
+    // Give the connection a moment to either establish or fail before reporting status.
+    thread::sleep(Duration::from_millis(20));
+
+    if spawned.is_finished() {
+        PlatformStatus {
+            listener: "unavailable (X11 connection failed)".to_string(),
+            hotkeys: "Ctrl+Alt+V".to_string(),
+            clipboard_events: false,
+            dropped_events,
+        }
+    } else {
+        PlatformStatus {
+            listener: "active (XGrabKey + XTEST)".to_string(),
+            hotkeys: "Ctrl+Alt+V".to_string(),
+            clipboard_events: false,
+            dropped_events,
+        }
+    }
+}
+
+fn run_event_loop() {
+    let Ok((conn, screen_num)) = x11rb::connect(None) else {
+        return;
+    };
+    let root = conn.setup().roots[screen_num].root;
+
+    let Some(keycode) = keysym_to_keycode(&conn, XK_V) else {
+        return;
+    };
+    let hotkey_mods = ModMask::CONTROL | ModMask::M1;
+
+    // Grab with every combination of CapsLock (LOCK) and NumLock (usually M2),
+    // since those show up in the reported event state too.
+    for ignored in [ModMask::from(0u16), ModMask::LOCK, ModMask::M2, ModMask::LOCK | ModMask::M2] {
+        let _ = conn.grab_key(
+            true,
+            root,
+            hotkey_mods | ignored,
+            keycode,
+            GrabMode::ASYNC,
+            GrabMode::ASYNC,
+        );
+    }
+    let _ = conn.flush();
+
+    loop {
+        let Ok(event) = conn.wait_for_event() else {
+            return;
+        };
+
+        if let Event::KeyPress(key_press) = event {
+            if key_press.detail == keycode && !INJECTING_PASTE.load(Ordering::Acquire) {
+                if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+                    super::super::send_event(sender, dropped, PlatformEvent::PasteRequested);
+                }
+            }
+        }
+    }
+}
+
+pub fn simulate_paste() -> Result<(), String> {
+    let (conn, _screen_num) =
+        x11rb::connect(None).map_err(|err| format!("X11 connect failed: {err}"))?;
+
+    let ctrl_code = *resolve_keycode(&conn, &CONTROL_KEYCODE, XK_CONTROL_L)?;
+    let v_code = *resolve_keycode(&conn, &V_KEYCODE, XK_V)?;
+
+    INJECTING_PASTE.store(true, Ordering::Release);
+    let result = (|| -> Result<(), String> {
+        fake_key(&conn, ctrl_code, true)?;
+        fake_key(&conn, v_code, true)?;
+        fake_key(&conn, v_code, false)?;
+        fake_key(&conn, ctrl_code, false)?;
+        conn.flush().map_err(|err| format!("flush failed: {err}"))
+    })();
+    INJECTING_PASTE.store(false, Ordering::Release);
+
+    result
+}
+
+fn resolve_keycode<'a>(
+    conn: &impl Connection,
+    cache: &'a OnceLock<u8>,
+    keysym: u32,
+) -> Result<&'a u8, String> {
+    if let Some(code) = cache.get() {
+        return Ok(code);
+    }
+    let code = keysym_to_keycode(conn, keysym)
+        .ok_or_else(|| format!("could not resolve keycode for keysym 0x{keysym:04x}"))?;
+    Ok(cache.get_or_init(|| code))
+}
+
+fn fake_key(conn: &impl Connection, keycode: u8, press: bool) -> Result<(), String> {
+    let event_type = if press { KEY_PRESS_EVENT } else { KEY_RELEASE_EVENT };
+    conn.xtest_fake_input(event_type, keycode, 0, x11rb::NONE, 0, 0, 0)
+        .map_err(|err| format!("XTestFakeInput failed: {err}"))?;
+    Ok(())
+}
+
+fn keysym_to_keycode(conn: &impl Connection, keysym: u32) -> Option<u8> {
+    let setup = conn.setup();
+    let count = setup.max_keycode - setup.min_keycode + 1;
+    let mapping = conn
+        .get_keyboard_mapping(setup.min_keycode, count)
+        .ok()?
+        .reply()
+        .ok()?;
+
+    let per_keycode = mapping.keysyms_per_keycode as usize;
+    for (index, chunk) in mapping.keysyms.chunks(per_keycode).enumerate() {
+        if chunk.iter().any(|&sym| sym == keysym) {
+            return Some(setup.min_keycode + index as u8);
+        }
+    }
+    None
+}
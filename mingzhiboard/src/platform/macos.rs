@@ -1,22 +1,43 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::Sender;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
+use std::sync::Arc;
 use std::sync::OnceLock;
 use std::thread;
+use std::time::Duration;
 
 use core_foundation::runloop::CFRunLoop;
 use core_graphics::event::{
     CallbackResult, CGEvent, CGEventFlags, CGEventTap, CGEventTapLocation, CGEventTapOptions,
-    CGEventTapPlacement, CGEventType, EventField, KeyCode,
+    CGEventTapPlacement, CGEventType, EventField, KeyCode as CGKeyCode,
 };
 use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
+use crossbeam_channel::Sender;
+use objc::{class, msg_send, sel, sel_impl};
+use objc::runtime::Object;
 
+use crate::core::accelerator::{Accelerator, KeyCode, ModifierFlags};
 use super::{PlatformEvent, PlatformStatus};
 
 static EVENT_SENDER: OnceLock<Sender<PlatformEvent>> = OnceLock::new();
+static DROPPED_EVENTS: OnceLock<Arc<AtomicU32>> = OnceLock::new();
 static INJECTING_PASTE: AtomicBool = AtomicBool::new(false);
+static LAST_CHANGE_COUNT: AtomicI64 = AtomicI64::new(-1);
+static HOTKEY_MODS: OnceLock<ModifierFlags> = OnceLock::new();
+static HOTKEY_KEYCODE: OnceLock<Option<CGKeyCode>> = OnceLock::new();
 
-pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
+const CLIPBOARD_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub fn init(
+    event_tx: Sender<PlatformEvent>,
+    dropped_events: Arc<AtomicU32>,
+    accelerator: Accelerator,
+) -> PlatformStatus {
     let _ = EVENT_SENDER.set(event_tx);
+    let _ = DROPPED_EVENTS.set(dropped_events.clone());
+    let _ = HOTKEY_MODS.set(accelerator.mods);
+    let hotkey_keycode = keycode_to_cgkeycode(accelerator.key);
+    let _ = HOTKEY_KEYCODE.set(hotkey_keycode);
+
+    thread::spawn(watch_pasteboard_change_count);
 
     thread::spawn(|| {
         let tap = CGEventTap::with_enabled(
@@ -31,15 +52,16 @@ pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
 
                 let keycode =
                     event.get_integer_value_field(EventField::KEYBOARD_EVENT_KEYCODE) as u64;
-                if keycode == KeyCode::ANSI_V as u64 {
-                    let flags = event.flags();
-                    let has_cmd = flags.contains(CGEventFlags::CGEventFlagCommand);
-                    if has_cmd {
-                        if let Some(sender) = EVENT_SENDER.get() {
-                            let _ = sender.send(PlatformEvent::PasteRequested);
-                        }
-                        return CallbackResult::Drop;
+                let hotkey_keycode = HOTKEY_KEYCODE.get().copied().flatten();
+                let hotkey_mods = HOTKEY_MODS.get().copied().unwrap_or(ModifierFlags::NONE);
+
+                if hotkey_keycode == Some(keycode as CGKeyCode)
+                    && required_mods_held(event.flags(), hotkey_mods)
+                {
+                    if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+                        super::send_event(sender, dropped, PlatformEvent::PasteRequested);
                     }
+                    return CallbackResult::Drop;
                 }
 
                 CallbackResult::Keep
@@ -50,9 +72,146 @@ pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
         let _ = tap;
     });
 
+    let hotkeys = if hotkey_keycode.is_some() {
+        accelerator.to_string()
+    } else {
+        format!("{accelerator} (unsupported key)")
+    };
+
     PlatformStatus {
         listener: "active (CGEventTap)".to_string(),
-        hotkeys: "Command+V".to_string(),
+        hotkeys,
+        clipboard_events: true,
+        dropped_events,
+    }
+}
+
+fn required_mods_held(flags: CGEventFlags, mods: ModifierFlags) -> bool {
+    (!mods.contains(ModifierFlags::CONTROL) || flags.contains(CGEventFlags::CGEventFlagControl))
+        && (!mods.contains(ModifierFlags::ALT) || flags.contains(CGEventFlags::CGEventFlagAlternate))
+        && (!mods.contains(ModifierFlags::SHIFT) || flags.contains(CGEventFlags::CGEventFlagShift))
+        && (!mods.contains(ModifierFlags::SUPER) || flags.contains(CGEventFlags::CGEventFlagCommand))
+}
+
+/// Maps a parsed accelerator key to the macOS virtual keycode the
+/// `CGEventTap` callback compares against. macOS keycodes are a fixed,
+/// non-alphabetical table (`ANSI_A` through `ANSI_Z`, etc.), so unlike
+/// Windows this is a direct lookup rather than an ASCII cast.
+fn keycode_to_cgkeycode(key: KeyCode) -> Option<CGKeyCode> {
+    match key {
+        KeyCode::Letter(ch) => letter_to_cgkeycode(ch),
+        KeyCode::Digit(n) => digit_to_cgkeycode(n),
+        KeyCode::Function(n) => function_to_cgkeycode(n),
+        KeyCode::Punctuation(ch) => punctuation_to_cgkeycode(ch),
+    }
+}
+
+fn letter_to_cgkeycode(ch: char) -> Option<CGKeyCode> {
+    Some(match ch {
+        'A' => CGKeyCode::ANSI_A,
+        'B' => CGKeyCode::ANSI_B,
+        'C' => CGKeyCode::ANSI_C,
+        'D' => CGKeyCode::ANSI_D,
+        'E' => CGKeyCode::ANSI_E,
+        'F' => CGKeyCode::ANSI_F,
+        'G' => CGKeyCode::ANSI_G,
+        'H' => CGKeyCode::ANSI_H,
+        'I' => CGKeyCode::ANSI_I,
+        'J' => CGKeyCode::ANSI_J,
+        'K' => CGKeyCode::ANSI_K,
+        'L' => CGKeyCode::ANSI_L,
+        'M' => CGKeyCode::ANSI_M,
+        'N' => CGKeyCode::ANSI_N,
+        'O' => CGKeyCode::ANSI_O,
+        'P' => CGKeyCode::ANSI_P,
+        'Q' => CGKeyCode::ANSI_Q,
+        'R' => CGKeyCode::ANSI_R,
+        'S' => CGKeyCode::ANSI_S,
+        'T' => CGKeyCode::ANSI_T,
+        'U' => CGKeyCode::ANSI_U,
+        'V' => CGKeyCode::ANSI_V,
+        'W' => CGKeyCode::ANSI_W,
+        'X' => CGKeyCode::ANSI_X,
+        'Y' => CGKeyCode::ANSI_Y,
+        'Z' => CGKeyCode::ANSI_Z,
+        _ => return None,
+    })
+}
+
+fn digit_to_cgkeycode(n: u8) -> Option<CGKeyCode> {
+    Some(match n {
+        0 => CGKeyCode::ANSI_0,
+        1 => CGKeyCode::ANSI_1,
+        2 => CGKeyCode::ANSI_2,
+        3 => CGKeyCode::ANSI_3,
+        4 => CGKeyCode::ANSI_4,
+        5 => CGKeyCode::ANSI_5,
+        6 => CGKeyCode::ANSI_6,
+        7 => CGKeyCode::ANSI_7,
+        8 => CGKeyCode::ANSI_8,
+        9 => CGKeyCode::ANSI_9,
+        _ => return None,
+    })
+}
+
+fn function_to_cgkeycode(n: u8) -> Option<CGKeyCode> {
+    Some(match n {
+        1 => CGKeyCode::F1,
+        2 => CGKeyCode::F2,
+        3 => CGKeyCode::F3,
+        4 => CGKeyCode::F4,
+        5 => CGKeyCode::F5,
+        6 => CGKeyCode::F6,
+        7 => CGKeyCode::F7,
+        8 => CGKeyCode::F8,
+        9 => CGKeyCode::F9,
+        10 => CGKeyCode::F10,
+        11 => CGKeyCode::F11,
+        12 => CGKeyCode::F12,
+        13 => CGKeyCode::F13,
+        14 => CGKeyCode::F14,
+        15 => CGKeyCode::F15,
+        16 => CGKeyCode::F16,
+        17 => CGKeyCode::F17,
+        18 => CGKeyCode::F18,
+        19 => CGKeyCode::F19,
+        20 => CGKeyCode::F20,
+        // core-graphics doesn't expose named constants past F20.
+        _ => return None,
+    })
+}
+
+fn punctuation_to_cgkeycode(ch: char) -> Option<CGKeyCode> {
+    Some(match ch {
+        '-' => CGKeyCode::ANSI_Minus,
+        '=' => CGKeyCode::ANSI_Equal,
+        ',' => CGKeyCode::ANSI_Comma,
+        '.' => CGKeyCode::ANSI_Period,
+        '/' => CGKeyCode::ANSI_Slash,
+        ';' => CGKeyCode::ANSI_Semicolon,
+        _ => return None,
+    })
+}
+
+/// Lightweight thread that watches `NSPasteboard.general.changeCount`, which
+/// macOS increments on every clipboard write. There is no OS-level push
+/// notification for pasteboard changes, so this is the standard way native
+/// macOS apps detect copies without diffing the clipboard contents.
+fn watch_pasteboard_change_count() {
+    let pasteboard: *mut Object = unsafe {
+        let cls = class!(NSPasteboard);
+        msg_send![cls, generalPasteboard]
+    };
+
+    loop {
+        let change_count: i64 = unsafe { msg_send![pasteboard, changeCount] };
+        let previous = LAST_CHANGE_COUNT.swap(change_count, Ordering::AcqRel);
+        if previous != -1 && change_count != previous {
+            if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+                super::send_event(sender, dropped, PlatformEvent::ClipboardChanged);
+            }
+        }
+        thread::sleep(CLIPBOARD_POLL_INTERVAL);
     }
 }
 
@@ -60,11 +219,11 @@ pub fn simulate_paste() -> Result<(), String> {
     let source = CGEventSource::new(CGEventSourceStateID::CombinedSessionState)
         .map_err(|_| "CGEventSourceCreate failed".to_string())?;
 
-    let mut key_down = CGEvent::new_keyboard_event(source.clone(), KeyCode::ANSI_V, true)
+    let mut key_down = CGEvent::new_keyboard_event(source.clone(), CGKeyCode::ANSI_V, true)
         .ok_or_else(|| "CGEventCreateKeyboardEvent failed".to_string())?;
     key_down.set_flags(CGEventFlags::CGEventFlagCommand);
 
-    let mut key_up = CGEvent::new_keyboard_event(source, KeyCode::ANSI_V, false)
+    let mut key_up = CGEvent::new_keyboard_event(source, CGKeyCode::ANSI_V, false)
         .ok_or_else(|| "CGEventCreateKeyboardEvent failed".to_string())?;
     key_up.set_flags(CGEventFlags::CGEventFlagCommand);
 
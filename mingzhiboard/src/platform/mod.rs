@@ -1,14 +1,39 @@
-use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+use crossbeam_channel::{Receiver, Sender};
+
+use crate::core::Accelerator;
+
+/// Capacity of the platform-to-app event channel. Bounded so a burst of
+/// hotkey presses the UI can't keep up with can't grow memory without limit;
+/// events beyond this just get dropped and counted (see `dropped_events`).
+const EVENT_QUEUE_CAPACITY: usize = 64;
 
 #[derive(Debug, Clone)]
 pub struct PlatformStatus {
     pub listener: String,
     pub hotkeys: String,
+    /// Whether the backend sends `PlatformEvent::ClipboardChanged` (from OS
+    /// notifications on Windows, from a `changeCount` watcher thread on
+    /// macOS), so the app can skip its fixed-interval `poll_clipboard` fallback.
+    pub clipboard_events: bool,
+    dropped_events: Arc<AtomicU32>,
+}
+
+impl PlatformStatus {
+    /// Hotkey/clipboard events dropped since startup because the bounded
+    /// event channel was full, e.g. a burst of paste presses faster than the
+    /// UI thread's `update` loop can drain them.
+    pub fn dropped_events(&self) -> u32 {
+        self.dropped_events.load(Ordering::Relaxed)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum PlatformEvent {
     PasteRequested,
+    ClipboardChanged,
 }
 
 pub struct PlatformHooks {
@@ -17,9 +42,10 @@ pub struct PlatformHooks {
 }
 
 impl PlatformHooks {
-    pub fn new() -> Self {
-        let (event_tx, event_rx) = mpsc::channel();
-        let status = init(event_tx);
+    pub fn new(accelerator: Accelerator) -> Self {
+        let (event_tx, event_rx) = crossbeam_channel::bounded(EVENT_QUEUE_CAPACITY);
+        let dropped_events = Arc::new(AtomicU32::new(0));
+        let status = init(event_tx, dropped_events, accelerator);
         Self { status, event_rx }
     }
 
@@ -28,25 +54,54 @@ impl PlatformHooks {
     }
 }
 
+/// Sends a platform event without blocking, counting it as dropped if the
+/// channel is full rather than stalling the hook/callback that produced it.
+pub(crate) fn send_event(
+    sender: &Sender<PlatformEvent>,
+    dropped_events: &AtomicU32,
+    event: PlatformEvent,
+) {
+    if sender.try_send(event).is_err() {
+        dropped_events.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
 #[cfg(target_os = "windows")]
 mod windows;
 #[cfg(target_os = "macos")]
 mod macos;
+#[cfg(target_os = "linux")]
+mod linux;
 
-pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
+pub fn init(
+    event_tx: Sender<PlatformEvent>,
+    dropped_events: Arc<AtomicU32>,
+    accelerator: Accelerator,
+) -> PlatformStatus {
     #[cfg(target_os = "windows")]
     {
-        return windows::init(event_tx);
+        return windows::init(event_tx, dropped_events, accelerator);
     }
     #[cfg(target_os = "macos")]
     {
-        return macos::init(event_tx);
+        return macos::init(event_tx, dropped_events, accelerator);
+    }
+    #[cfg(target_os = "linux")]
+    {
+        // The Linux backends don't read the configurable accelerator: X11
+        // grabs a fixed Ctrl+Alt+V and Wayland has no global hotkey (see
+        // platform::linux).
+        let _ = accelerator;
+        return linux::init(event_tx, dropped_events);
     }
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
+        let _ = accelerator;
         PlatformStatus {
             listener: "unsupported platform".to_string(),
             hotkeys: "unsupported platform".to_string(),
+            clipboard_events: false,
+            dropped_events,
         }
     }
 }
@@ -60,7 +115,11 @@ pub fn simulate_paste() -> Result<(), String> {
     {
         return macos::simulate_paste();
     }
-    #[cfg(not(any(target_os = "windows", target_os = "macos")))]
+    #[cfg(target_os = "linux")]
+    {
+        return linux::simulate_paste();
+    }
+    #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
     {
         Err("paste injection not supported on this platform".to_string())
     }
@@ -1,26 +1,47 @@
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::Sender;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 use std::sync::OnceLock;
 use std::thread;
 
-use windows::Win32::Foundation::{HINSTANCE, LPARAM, LRESULT, WPARAM};
+use crossbeam_channel::Sender;
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{HINSTANCE, HWND, LPARAM, LRESULT, WPARAM};
+use windows::Win32::System::LibraryLoader::GetModuleHandleW;
 use windows::Win32::UI::Input::KeyboardAndMouse::{
     GetAsyncKeyState, SendInput, INPUT, INPUT_0, INPUT_KEYBOARD, KEYBDINPUT, KEYBD_EVENT_FLAGS,
-    KEYEVENTF_KEYUP, VK_CONTROL, VK_MENU, VK_Q, VK_V,
+    KEYEVENTF_KEYUP, VIRTUAL_KEY, VK_CONTROL, VK_LWIN, VK_MENU, VK_RWIN, VK_SHIFT, VK_V,
 };
 use windows::Win32::UI::WindowsAndMessaging::{
-    CallNextHookEx, DispatchMessageW, GetMessageW, SetWindowsHookExW, TranslateMessage,
-    UnhookWindowsHookEx, KBDLLHOOKSTRUCT, MSG, WH_KEYBOARD_LL, WM_KEYDOWN, WM_KEYUP,
-    WM_SYSKEYDOWN, WM_SYSKEYUP,
+    AddClipboardFormatListener, CallNextHookEx, CreateWindowExW, DefWindowProcW,
+    DispatchMessageW, GetMessageW, RegisterClassW, SetWindowsHookExW, TranslateMessage,
+    UnhookWindowsHookEx, CW_USEDEFAULT, HWND_MESSAGE, KBDLLHOOKSTRUCT, MSG, WINDOW_EX_STYLE,
+    WINDOW_STYLE, WM_CLIPBOARDUPDATE, WM_KEYDOWN, WM_KEYUP, WM_SYSKEYDOWN, WM_SYSKEYUP,
+    WNDCLASSW, WH_KEYBOARD_LL,
 };
 
+use crate::core::accelerator::{Accelerator, KeyCode, ModifierFlags};
 use super::{PlatformEvent, PlatformStatus};
 
 static EVENT_SENDER: OnceLock<Sender<PlatformEvent>> = OnceLock::new();
+static DROPPED_EVENTS: OnceLock<Arc<AtomicU32>> = OnceLock::new();
 static INJECTING_PASTE: AtomicBool = AtomicBool::new(false);
+static CLIPBOARD_LISTENER_REGISTERED: AtomicBool = AtomicBool::new(false);
+static HOTKEY_MODS: OnceLock<ModifierFlags> = OnceLock::new();
+static HOTKEY_VK: OnceLock<Option<VIRTUAL_KEY>> = OnceLock::new();
+
+const CLIPBOARD_WINDOW_CLASS: PCWSTR = windows::core::w!("MingzhiBoardClipboardListener");
 
-pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
+pub fn init(
+    event_tx: Sender<PlatformEvent>,
+    dropped_events: Arc<AtomicU32>,
+    accelerator: Accelerator,
+) -> PlatformStatus {
     let _ = EVENT_SENDER.set(event_tx);
+    let _ = DROPPED_EVENTS.set(dropped_events.clone());
+    let _ = HOTKEY_MODS.set(accelerator.mods);
+    let hotkey_vk = keycode_to_vk(accelerator.key);
+    let _ = HOTKEY_VK.set(hotkey_vk);
 
     thread::spawn(|| unsafe {
         let hook = match SetWindowsHookExW(
@@ -33,6 +54,13 @@ pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
             Err(_) => return,
         };
 
+        let clipboard_hwnd = create_clipboard_listener_window();
+        if let Some(hwnd) = clipboard_hwnd {
+            if AddClipboardFormatListener(hwnd).is_ok() {
+                CLIPBOARD_LISTENER_REGISTERED.store(true, Ordering::Release);
+            }
+        }
+
         let mut message = MSG::default();
         while GetMessageW(&mut message, None, 0, 0).as_bool() {
             TranslateMessage(&message);
@@ -42,12 +70,123 @@ pub fn init(event_tx: Sender<PlatformEvent>) -> PlatformStatus {
         let _ = UnhookWindowsHookEx(hook);
     });
 
+    // AddClipboardFormatListener needs the message loop above to have created
+    // its window and registered before we can report whether it worked; the
+    // window step runs synchronously at the top of that loop, so a short
+    // settle here is enough for the common case. If it hasn't flipped by
+    // then, `clipboard_events` is reported as false and stays false, so the
+    // app keeps polling for the rest of the session.
+    thread::sleep(std::time::Duration::from_millis(20));
+
+    let hotkeys = if hotkey_vk.is_some() {
+        accelerator.to_string()
+    } else {
+        format!("{accelerator} (unsupported key on this layout)")
+    };
+
     PlatformStatus {
         listener: "active (WH_KEYBOARD_LL)".to_string(),
-        hotkeys: "Alt+Q".to_string(),
+        hotkeys,
+        clipboard_events: CLIPBOARD_LISTENER_REGISTERED.load(Ordering::Acquire),
+        dropped_events,
     }
 }
 
+/// Maps a parsed accelerator key to the Windows virtual-key code the
+/// low-level keyboard hook compares against. Letters and digits share their
+/// ASCII codepoints with their virtual-key constants; function keys are
+/// contiguous starting at `VK_F1` (0x70); punctuation support is limited to
+/// the handful of OEM keys with a stable US-layout mapping.
+fn keycode_to_vk(key: KeyCode) -> Option<VIRTUAL_KEY> {
+    const VK_F1: u16 = 0x70;
+
+    match key {
+        KeyCode::Letter(ch) => Some(VIRTUAL_KEY(ch as u16)),
+        KeyCode::Digit(n) => Some(VIRTUAL_KEY(b'0' as u16 + n as u16)),
+        KeyCode::Function(n) if (1..=24).contains(&n) => {
+            Some(VIRTUAL_KEY(VK_F1 + (n - 1) as u16))
+        }
+        KeyCode::Function(_) => None,
+        KeyCode::Punctuation(ch) => oem_vk_for_punctuation(ch),
+    }
+}
+
+fn oem_vk_for_punctuation(ch: char) -> Option<VIRTUAL_KEY> {
+    // VK_OEM_* constants for the common US-layout punctuation keys.
+    const VK_OEM_MINUS: u16 = 0xBD;
+    const VK_OEM_PLUS: u16 = 0xBB;
+    const VK_OEM_COMMA: u16 = 0xBC;
+    const VK_OEM_PERIOD: u16 = 0xBE;
+    const VK_OEM_2: u16 = 0xBF; // '/'
+    const VK_OEM_1: u16 = 0xBA; // ';'
+
+    match ch {
+        '-' => Some(VIRTUAL_KEY(VK_OEM_MINUS)),
+        '=' => Some(VIRTUAL_KEY(VK_OEM_PLUS)),
+        ',' => Some(VIRTUAL_KEY(VK_OEM_COMMA)),
+        '.' => Some(VIRTUAL_KEY(VK_OEM_PERIOD)),
+        '/' => Some(VIRTUAL_KEY(VK_OEM_2)),
+        ';' => Some(VIRTUAL_KEY(VK_OEM_1)),
+        _ => None,
+    }
+}
+
+fn are_required_mods_held(mods: ModifierFlags) -> bool {
+    let key_down = |vk: windows::Win32::UI::Input::KeyboardAndMouse::VIRTUAL_KEY| unsafe {
+        GetAsyncKeyState(vk.0 as i32) as u16 & 0x8000 != 0
+    };
+
+    (!mods.contains(ModifierFlags::CONTROL) || key_down(VK_CONTROL))
+        && (!mods.contains(ModifierFlags::ALT) || key_down(VK_MENU))
+        && (!mods.contains(ModifierFlags::SHIFT) || key_down(VK_SHIFT))
+        && (!mods.contains(ModifierFlags::SUPER) || key_down(VK_LWIN) || key_down(VK_RWIN))
+}
+
+unsafe fn create_clipboard_listener_window() -> Option<HWND> {
+    let instance = HINSTANCE(GetModuleHandleW(None).ok()?.0);
+
+    let class = WNDCLASSW {
+        lpfnWndProc: Some(clipboard_window_proc),
+        hInstance: instance,
+        lpszClassName: CLIPBOARD_WINDOW_CLASS,
+        ..Default::default()
+    };
+    if RegisterClassW(&class) == 0 {
+        return None;
+    }
+
+    CreateWindowExW(
+        WINDOW_EX_STYLE::default(),
+        CLIPBOARD_WINDOW_CLASS,
+        PCWSTR::null(),
+        WINDOW_STYLE::default(),
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        CW_USEDEFAULT,
+        Some(HWND_MESSAGE),
+        None,
+        Some(instance),
+        None,
+    )
+    .ok()
+}
+
+unsafe extern "system" fn clipboard_window_proc(
+    hwnd: HWND,
+    msg: u32,
+    wparam: WPARAM,
+    lparam: LPARAM,
+) -> LRESULT {
+    if msg == WM_CLIPBOARDUPDATE {
+        if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+            super::send_event(sender, dropped, PlatformEvent::ClipboardChanged);
+        }
+        return LRESULT(0);
+    }
+    DefWindowProcW(hwnd, msg, wparam, lparam)
+}
+
 pub fn simulate_paste() -> Result<(), String> {
     let mut inputs = Vec::with_capacity(6);
     let alt_down = unsafe { GetAsyncKeyState(VK_MENU.0 as i32) } as u16 & 0x8000 != 0;
@@ -106,14 +245,14 @@ unsafe extern "system" fn keyboard_hook(code: i32, wparam: WPARAM, lparam: LPARA
         return CallNextHookEx(None, code, wparam, lparam);
     }
 
-    if is_keydown && vk_code == VK_Q.0 as u32 {
-        let alt = (GetAsyncKeyState(VK_MENU.0 as i32) as u16 & 0x8000) != 0;
-        if alt {
-            if let Some(sender) = EVENT_SENDER.get() {
-                let _ = sender.send(PlatformEvent::PasteRequested);
-            }
-            return LRESULT(1);
+    let hotkey_vk = HOTKEY_VK.get().copied().flatten();
+    let hotkey_mods = HOTKEY_MODS.get().copied().unwrap_or(ModifierFlags::NONE);
+
+    if is_keydown && hotkey_vk == Some(VIRTUAL_KEY(vk_code as u16)) && are_required_mods_held(hotkey_mods) {
+        if let (Some(sender), Some(dropped)) = (EVENT_SENDER.get(), DROPPED_EVENTS.get()) {
+            super::send_event(sender, dropped, PlatformEvent::PasteRequested);
         }
+        return LRESULT(1);
     }
 
     CallNextHookEx(None, code, wparam, lparam)
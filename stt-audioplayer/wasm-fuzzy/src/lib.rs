@@ -8,7 +8,12 @@ const DEFAULT_TOP_K: usize = 20;
 const SCORE_EXACT: i32 = 2;
 const SCORE_FUZZY: i32 = 1;
 const SCORE_MISMATCH: i32 = -1;
-const SCORE_GAP: i32 = -1;
+// Gotoh affine-gap defaults: opening a gap costs `gap_open`, each further
+// word the gap swallows costs only `gap_extend`, so a transcript that skips
+// a whole clause isn't penalized as harshly per word as scattered
+// single-word gaps would be.
+const DEFAULT_GAP_OPEN: i32 = 2;
+const DEFAULT_GAP_EXTEND: i32 = 1;
 
 #[derive(Clone, Serialize, Deserialize)]
 struct Window {
@@ -18,11 +23,32 @@ struct Window {
     tokens: Vec<String>,
 }
 
+/// Whether a matched-window word was an exact transcript match, a
+/// fuzzy/substituted one, or a gap the transcript skipped over entirely.
+#[derive(Clone, Serialize, Deserialize)]
+enum WordMatchKind {
+    Exact,
+    Fuzzy,
+    Gap,
+}
+
+/// A character range into `QueryResult::matched_text`, for a UI to highlight
+/// aligned words and dim filler/gaps.
+#[derive(Clone, Serialize, Deserialize)]
+struct HighlightSpan {
+    char_start: usize,
+    char_end: usize,
+    kind: WordMatchKind,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 struct AlignmentResult {
     alignment_score: i32,
     start_index_in_window: usize,
     end_index_in_window: usize,
+    /// One entry per window token in `[start_index_in_window, end_index_in_window]`,
+    /// in order, recorded during traceback.
+    word_flags: Vec<WordMatchKind>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -33,6 +59,43 @@ struct QueryResult {
     matched_text: String,
     alignment_score: f64,
     confidence: f64,
+    highlights: Vec<HighlightSpan>,
+}
+
+/// One candidate window kept by `query_top_k_internal`'s bounded heap,
+/// ordered by alignment score. `confidence` is derived from
+/// `alignment_score` for a fixed transcript length, so it can never break a
+/// tie that the score didn't already break; ties instead resolve on
+/// `window_id` so the heap order (and thus `query_top_k`'s output) is
+/// deterministic rather than depending on iteration order over
+/// `candidate_ids`.
+struct RankedWindow {
+    alignment_score: i32,
+    confidence: f64,
+    window_id: usize,
+    alignment: AlignmentResult,
+}
+
+impl PartialEq for RankedWindow {
+    fn eq(&self, other: &Self) -> bool {
+        self.alignment_score == other.alignment_score && self.window_id == other.window_id
+    }
+}
+
+impl Eq for RankedWindow {}
+
+impl PartialOrd for RankedWindow {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedWindow {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.alignment_score
+            .cmp(&other.alignment_score)
+            .then_with(|| self.window_id.cmp(&other.window_id))
+    }
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -40,8 +103,53 @@ struct TextLocatorState {
     words: Vec<String>,
     windows: Vec<Window>,
     inverted_index: HashMap<String, HashSet<usize>>,
+    /// Secondary index keyed by each word's anagram key (see `anagram_key`),
+    /// so a query can find windows containing a transposed or
+    /// single-character-off spelling of a transcript word even when its
+    /// 3-grams don't overlap. Keyed by `String` rather than an enum so the
+    /// map round-trips through `serde_json` (its `MapKeySerializer` only
+    /// accepts primitive/string keys).
+    anagram_index: HashMap<String, HashSet<usize>>,
     window_size_words: usize,
     step_size_words: usize,
+    #[serde(default = "default_gap_open")]
+    gap_open: i32,
+    #[serde(default = "default_gap_extend")]
+    gap_extend: i32,
+    /// Synonym classes: each member word maps to the full set of words
+    /// (itself included) that should be treated as interchangeable, so a
+    /// prepared locator can ship its own domain vocabulary.
+    #[serde(default)]
+    synonyms: HashMap<String, HashSet<String>>,
+    #[serde(default)]
+    normalization_mode: NormalizationMode,
+}
+
+/// How `preprocess`/`query` tokenize text before indexing/aligning it.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+enum NormalizationMode {
+    /// Lowercase and collapse non-alphanumerics to spaces, then split on
+    /// whitespace; the crate's original behavior. CJK text has no whitespace
+    /// word boundaries, so it collapses into one giant token under this mode.
+    Latin,
+    /// Also folds common Latin diacritics/ligatures to their base letters,
+    /// and segments CJK codepoints one character per token instead of
+    /// relying on whitespace.
+    Multilingual,
+}
+
+impl Default for NormalizationMode {
+    fn default() -> Self {
+        NormalizationMode::Latin
+    }
+}
+
+fn default_gap_open() -> i32 {
+    DEFAULT_GAP_OPEN
+}
+
+fn default_gap_extend() -> i32 {
+    DEFAULT_GAP_EXTEND
 }
 
 #[wasm_bindgen]
@@ -58,15 +166,64 @@ impl TextLocator {
                 words: Vec::new(),
                 windows: Vec::new(),
                 inverted_index: HashMap::new(),
+                anagram_index: HashMap::new(),
                 window_size_words: DEFAULT_WINDOW_SIZE_WORDS,
                 step_size_words: DEFAULT_STEP_SIZE_WORDS,
+                gap_open: DEFAULT_GAP_OPEN,
+                gap_extend: DEFAULT_GAP_EXTEND,
+                synonyms: HashMap::new(),
+                normalization_mode: NormalizationMode::Latin,
             },
         }
     }
 
+    /// Overrides the affine gap penalties used by the Smith-Waterman-Gotoh
+    /// aligner. `gap_open` is charged once per contiguous run of skipped
+    /// words, `gap_extend` for each additional word the run swallows.
+    #[wasm_bindgen(js_name = setGapPenalties)]
+    pub fn set_gap_penalties(&mut self, gap_open: i32, gap_extend: i32) {
+        self.state.gap_open = gap_open;
+        self.state.gap_extend = gap_extend;
+    }
+
+    /// Declares `word` and `synonyms` interchangeable for matching purposes:
+    /// `word_match_score` scores them as an exact match, and `query_internal`
+    /// probes the inverted index under every member's spelling. Merges into
+    /// any synonym classes `word` or its synonyms already belong to, so
+    /// repeated calls can grow one class incrementally.
+    #[wasm_bindgen(js_name = addSynonyms)]
+    pub fn add_synonyms(&mut self, word: &str, synonyms: Vec<String>) {
+        let mut group: HashSet<String> = HashSet::new();
+        group.insert(word.to_string());
+        if let Some(existing) = self.state.synonyms.get(word) {
+            group.extend(existing.iter().cloned());
+        }
+        for synonym in &synonyms {
+            group.insert(synonym.clone());
+            if let Some(existing) = self.state.synonyms.get(synonym) {
+                group.extend(existing.iter().cloned());
+            }
+        }
+        for member in &group {
+            self.state.synonyms.insert(member.clone(), group.clone());
+        }
+    }
+
+    /// Enables Unicode-folding/CJK-aware normalization for `preprocess` and
+    /// `query` (see `NormalizationMode::Multilingual`). Off by default, which
+    /// preserves the original Latin-text tokenizing behavior.
+    #[wasm_bindgen(js_name = setMultilingualNormalization)]
+    pub fn set_multilingual_normalization(&mut self, enabled: bool) {
+        self.state.normalization_mode = if enabled {
+            NormalizationMode::Multilingual
+        } else {
+            NormalizationMode::Latin
+        };
+    }
+
     #[wasm_bindgen]
     pub fn preprocess(&mut self, book_text: &str) {
-        let normalized = normalize_text(book_text);
+        let normalized = normalize_text_with_mode(book_text, self.state.normalization_mode);
         let words: Vec<String> = normalized
             .split_whitespace()
             .filter(|w| !w.is_empty())
@@ -75,6 +232,7 @@ impl TextLocator {
 
         let mut windows = Vec::new();
         let mut inverted_index: HashMap<String, HashSet<usize>> = HashMap::new();
+        let mut anagram_index: HashMap<String, HashSet<usize>> = HashMap::new();
 
         let mut start = 0usize;
         let mut window_id = 0usize;
@@ -94,6 +252,12 @@ impl TextLocator {
             for gram in generate_token_ngrams(&tokens, 3) {
                 inverted_index.entry(gram).or_default().insert(window_id);
             }
+            for token in &tokens {
+                anagram_index
+                    .entry(anagram_key(token))
+                    .or_default()
+                    .insert(window_id);
+            }
 
             windows.push(window);
             window_id += 1;
@@ -107,6 +271,7 @@ impl TextLocator {
         self.state.words = words;
         self.state.windows = windows;
         self.state.inverted_index = inverted_index;
+        self.state.anagram_index = anagram_index;
     }
 
     #[wasm_bindgen]
@@ -117,12 +282,29 @@ impl TextLocator {
         }
     }
 
+    /// Like `query`, but returns up to `k` aligned windows ranked by
+    /// alignment score instead of only the single best match. Useful when a
+    /// snippet legitimately recurs in several places (repeated refrains,
+    /// boilerplate) and the caller wants to disambiguate with surrounding
+    /// context rather than getting an arbitrary winner.
+    #[wasm_bindgen(js_name = queryTopK)]
+    pub fn query_top_k(&self, transcript_snippet: &str, k: usize) -> JsValue {
+        let results = self.query_top_k_internal(transcript_snippet, k);
+        serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
+    }
+
     fn query_internal(&self, transcript_snippet: &str) -> Option<QueryResult> {
-        if self.state.windows.is_empty() {
-            return None;
+        self.query_top_k_internal(transcript_snippet, 1)
+            .into_iter()
+            .next()
+    }
+
+    fn query_top_k_internal(&self, transcript_snippet: &str, k: usize) -> Vec<QueryResult> {
+        if self.state.windows.is_empty() || k == 0 {
+            return Vec::new();
         }
 
-        let transcript_norm = normalize_text(transcript_snippet);
+        let transcript_norm = normalize_text_with_mode(transcript_snippet, self.state.normalization_mode);
         let transcript_tokens: Vec<String> = transcript_norm
             .split_whitespace()
             .filter(|w| !w.is_empty())
@@ -130,10 +312,10 @@ impl TextLocator {
             .collect();
 
         if transcript_tokens.is_empty() {
-            return None;
+            return Vec::new();
         }
 
-        let query_ngrams = generate_token_ngrams(&transcript_tokens, 3);
+        let query_ngrams = expand_ngrams_with_synonyms(&transcript_tokens, 3, &self.state.synonyms);
         let mut overlap_count: HashMap<usize, usize> = HashMap::new();
         for gram in query_ngrams {
             if let Some(window_ids) = self.state.inverted_index.get(&gram) {
@@ -156,12 +338,30 @@ impl TextLocator {
             candidate_ids = (0..self.state.windows.len().min(DEFAULT_TOP_K)).collect();
         }
 
-        let mut best_alignment = AlignmentResult {
-            alignment_score: i32::MIN,
-            start_index_in_window: 0,
-            end_index_in_window: 0,
-        };
-        let mut best_window_id: Option<usize> = None;
+        // Union in windows reachable through the anagram index, which catches
+        // transposed or single-character-off spellings the 3-gram overlap
+        // above would otherwise miss entirely.
+        let mut candidate_set: HashSet<usize> = candidate_ids.iter().copied().collect();
+        for token in &transcript_tokens {
+            for window_id in anagram_candidates(token, &self.state.anagram_index) {
+                if candidate_set.insert(window_id) {
+                    candidate_ids.push(window_id);
+                }
+            }
+        }
+
+        let automatons = build_automatons(&transcript_tokens, 1);
+        let transcript_len = transcript_tokens.len().max(1) as f64;
+
+        // Bounded max-heap of the best `k` windows seen so far, implemented
+        // as a `BinaryHeap` of `Reverse` entries so the *smallest* kept entry
+        // sits at the top and can be evicted in O(log k) once a better
+        // candidate arrives. Its current minimum also serves as the pruning
+        // bound passed into `smith_waterman_align`: a window whose alignment
+        // can't catch up to the worst entry we'd still keep can't make the
+        // top `k` either.
+        let mut heap: std::collections::BinaryHeap<std::cmp::Reverse<RankedWindow>> =
+            std::collections::BinaryHeap::with_capacity(k + 1);
 
         for window_id in candidate_ids {
             let window = match self.state.windows.get(window_id) {
@@ -169,41 +369,82 @@ impl TextLocator {
                 None => continue,
             };
 
+            // Only a full heap's minimum is a valid pruning cutoff: while
+            // `heap.len() < k` every positive-scoring window must still be
+            // kept, so pruning against a partial minimum here would let an
+            // early strong candidate cause a later, equally strong one to
+            // be under-scored and wrongly evicted in its place.
+            let bound = if heap.len() == k {
+                heap.peek().map(|std::cmp::Reverse(worst)| worst.alignment_score).unwrap_or(0)
+            } else {
+                0
+            };
+
             let alignment = smith_waterman_align(
                 &transcript_tokens,
                 &window.tokens,
-                best_alignment.alignment_score.max(0),
+                bound,
+                &automatons,
+                &self.state.synonyms,
+                self.state.gap_open,
+                self.state.gap_extend,
             );
-            if alignment.alignment_score > best_alignment.alignment_score {
-                best_alignment = alignment;
-                best_window_id = Some(window_id);
+            if alignment.alignment_score <= 0 {
+                continue;
+            }
+
+            let confidence = alignment.alignment_score as f64 / (2.0 * transcript_len);
+            let entry = RankedWindow {
+                alignment_score: alignment.alignment_score,
+                confidence,
+                window_id,
+                alignment,
+            };
+
+            if heap.len() < k {
+                heap.push(std::cmp::Reverse(entry));
+            } else if let Some(std::cmp::Reverse(worst)) = heap.peek() {
+                if entry > *worst {
+                    heap.pop();
+                    heap.push(std::cmp::Reverse(entry));
+                }
             }
         }
 
-        let window_id = match best_window_id {
-            Some(id) if best_alignment.alignment_score > 0 => id,
-            _ => return None,
-        };
+        // Ascending order of `Reverse<RankedWindow>` is descending order of
+        // `RankedWindow`, i.e. best-scoring window first.
+        heap.into_sorted_vec()
+            .into_iter()
+            .filter_map(|std::cmp::Reverse(entry)| {
+                self.build_query_result(entry.window_id, &entry.alignment, entry.confidence)
+            })
+            .collect()
+    }
 
-        let window = &self.state.windows[window_id];
-        let abs_start = window.start_word_index + best_alignment.start_index_in_window;
-        let abs_end = window.start_word_index + best_alignment.end_index_in_window;
+    fn build_query_result(
+        &self,
+        window_id: usize,
+        alignment: &AlignmentResult,
+        confidence: f64,
+    ) -> Option<QueryResult> {
+        let window = self.state.windows.get(window_id)?;
+        let abs_start = window.start_word_index + alignment.start_index_in_window;
+        let abs_end = window.start_word_index + alignment.end_index_in_window;
         if abs_start >= self.state.words.len() || abs_end >= self.state.words.len() || abs_start > abs_end {
             return None;
         }
 
         let matched_text = self.state.words[abs_start..=abs_end].join(" ");
-        let transcript_len = transcript_tokens.len().max(1) as f64;
-        let alignment_score = best_alignment.alignment_score as f64;
-        let confidence = alignment_score / (2.0 * transcript_len);
+        let highlights = highlight_spans(&self.state.words[abs_start..=abs_end], &alignment.word_flags);
 
         Some(QueryResult {
             window_id,
             start_word_index: abs_start,
             end_word_index: abs_end,
             matched_text,
-            alignment_score,
+            alignment_score: alignment.alignment_score as f64,
             confidence,
+            highlights,
         })
     }
 
@@ -218,8 +459,13 @@ impl TextLocator {
             words: Vec::new(),
             windows: Vec::new(),
             inverted_index: HashMap::new(),
+            anagram_index: HashMap::new(),
             window_size_words: DEFAULT_WINDOW_SIZE_WORDS,
             step_size_words: DEFAULT_STEP_SIZE_WORDS,
+            gap_open: DEFAULT_GAP_OPEN,
+            gap_extend: DEFAULT_GAP_EXTEND,
+            synonyms: HashMap::new(),
+            normalization_mode: NormalizationMode::Latin,
         });
         TextLocator { state }
     }
@@ -288,6 +534,74 @@ pub fn normalize_text(text: &str) -> String {
         .to_string()
 }
 
+fn normalize_text_with_mode(text: &str, mode: NormalizationMode) -> String {
+    match mode {
+        NormalizationMode::Latin => normalize_text(text),
+        NormalizationMode::Multilingual => normalize_multilingual(text),
+    }
+}
+
+/// Like `normalize_text`, but additionally folds common Latin
+/// diacritics/ligatures to their base letters and inserts word boundaries
+/// around each CJK codepoint, since those scripts carry no whitespace
+/// between words for `split_whitespace` to key off.
+fn normalize_multilingual(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.to_lowercase().chars() {
+        if is_cjk(c) {
+            out.push(' ');
+            out.push(c);
+            out.push(' ');
+            continue;
+        }
+        match fold_diacritic(c) {
+            Some(folded) => out.push_str(folded),
+            None if c.is_alphanumeric() => out.push(c),
+            None => out.push(' '),
+        }
+    }
+    out.split_whitespace()
+        .collect::<Vec<&str>>()
+        .join(" ")
+        .trim()
+        .to_string()
+}
+
+/// CJK Unified Ideographs (plus Extension A), Hiragana, Katakana, and Hangul
+/// Syllables: the scripts this crate segments one codepoint per token.
+fn is_cjk(c: char) -> bool {
+    matches!(c as u32,
+        0x3040..=0x30FF   // Hiragana + Katakana
+        | 0x3400..=0x4DBF // CJK Unified Ideographs Extension A
+        | 0x4E00..=0x9FFF // CJK Unified Ideographs
+        | 0xAC00..=0xD7A3 // Hangul Syllables
+    )
+}
+
+/// Folds a single character's common Latin diacritic or ligature to its base
+/// ASCII form, or `None` if `c` isn't one this table covers. Not an
+/// exhaustive Unicode normalization (that would pull in a dedicated crate);
+/// covers the accented letters most Latin-script book/transcript text uses.
+fn fold_diacritic(c: char) -> Option<&'static str> {
+    Some(match c {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' => "i",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' => "u",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'ç' | 'ć' | 'č' | 'ĉ' => "c",
+        'ś' | 'š' | 'ŝ' => "s",
+        'ź' | 'ž' | 'ż' => "z",
+        'ł' => "l",
+        'æ' => "ae",
+        'œ' => "oe",
+        'ß' => "ss",
+        _ => return None,
+    })
+}
+
 #[wasm_bindgen]
 pub fn n_gram_similarity(text1: &str, text2: &str, n: usize) -> f64 {
     let ngrams1 = generate_ngrams(text1, n);
@@ -318,6 +632,144 @@ fn generate_token_ngrams(tokens: &[String], n: usize) -> Vec<String> {
     out
 }
 
+/// Same n-grams as `generate_token_ngrams`, plus every variant reachable by
+/// substituting each word for a fellow member of its synonym class, so a
+/// transcript that says "vehicle" still probes the inverted index under
+/// windows indexed as "car". Guards against combinatorial blowup on large
+/// synonym classes by capping expansion per n-gram position.
+fn expand_ngrams_with_synonyms(
+    tokens: &[String],
+    n: usize,
+    synonyms: &HashMap<String, HashSet<String>>,
+) -> Vec<String> {
+    let base_ngrams = generate_token_ngrams(tokens, n);
+    if synonyms.is_empty() || n == 0 || tokens.len() < n {
+        return base_ngrams;
+    }
+
+    const MAX_VARIANTS_PER_NGRAM: usize = 64;
+    let mut expanded: HashSet<String> = base_ngrams.into_iter().collect();
+    for i in 0..=tokens.len() - n {
+        let window = &tokens[i..i + n];
+        let mut variants: Vec<Vec<String>> = vec![Vec::new()];
+        for word in window {
+            let choices: Vec<&String> = synonyms
+                .get(word)
+                .map(|group| group.iter().collect())
+                .unwrap_or_else(|| vec![word]);
+            let mut next_variants = Vec::with_capacity(variants.len() * choices.len());
+            'build: for variant in &variants {
+                for choice in &choices {
+                    let mut extended = variant.clone();
+                    extended.push((*choice).clone());
+                    next_variants.push(extended);
+                    if next_variants.len() >= MAX_VARIANTS_PER_NGRAM {
+                        break 'build;
+                    }
+                }
+            }
+            variants = next_variants;
+        }
+        for variant in variants {
+            expanded.insert(variant.join(" "));
+        }
+    }
+    expanded.into_iter().collect()
+}
+
+// One distinct prime per lowercase letter and digit, used to encode a word as
+// the product of its characters' primes: anagrams always share a product,
+// and the edit operations below correspond to simple arithmetic on it.
+const CHAR_PRIMES: [u128; 36] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151,
+];
+
+fn char_prime(c: char) -> Option<u128> {
+    match c {
+        'a'..='z' => Some(CHAR_PRIMES[(c as u32 - 'a' as u32) as usize]),
+        '0'..='9' => Some(CHAR_PRIMES[26 + (c as u32 - '0' as u32) as usize]),
+        _ => None,
+    }
+}
+
+/// Prefixed with `p` so a prime-product key can never collide with a
+/// sorted-chars fallback key (prefixed `s`) that happens to contain the same
+/// digits.
+fn product_key(value: u128) -> String {
+    format!("p{value}")
+}
+
+fn anagram_key(word: &str) -> String {
+    let mut product: Option<u128> = Some(1);
+    for c in word.chars() {
+        product = product.and_then(|p| char_prime(c).and_then(|prime| p.checked_mul(prime)));
+        if product.is_none() {
+            break;
+        }
+    }
+
+    match product {
+        Some(value) => product_key(value),
+        None => {
+            let mut chars: Vec<char> = word.chars().collect();
+            chars.sort_unstable();
+            let sorted: String = chars.into_iter().collect();
+            format!("s{sorted}")
+        }
+    }
+}
+
+/// Window ids indexed under `word`'s own anagram key, plus every key
+/// reachable by a single insertion, deletion, or substitution of a
+/// character's prime factor. Only words whose key is a prime product
+/// support that expansion; words that overflowed into the sorted-chars
+/// fallback only match exact anagrams.
+fn anagram_candidates(word: &str, anagram_index: &HashMap<String, HashSet<usize>>) -> HashSet<usize> {
+    let mut out = HashSet::new();
+    let key = anagram_key(word);
+    if let Some(ids) = anagram_index.get(&key) {
+        out.extend(ids);
+    }
+
+    if let Some(value) = key.strip_prefix('p').and_then(|rest| rest.parse::<u128>().ok()) {
+        for &inserted in CHAR_PRIMES.iter() {
+            if let Some(candidate) = value.checked_mul(inserted) {
+                if let Some(ids) = anagram_index.get(&product_key(candidate)) {
+                    out.extend(ids);
+                }
+            }
+        }
+
+        for &removed in CHAR_PRIMES.iter() {
+            if value % removed == 0 {
+                if let Some(ids) = anagram_index.get(&product_key(value / removed)) {
+                    out.extend(ids);
+                }
+            }
+        }
+
+        for &removed in CHAR_PRIMES.iter() {
+            if value % removed != 0 {
+                continue;
+            }
+            let base = value / removed;
+            for &added in CHAR_PRIMES.iter() {
+                if added == removed {
+                    continue;
+                }
+                if let Some(candidate) = base.checked_mul(added) {
+                    if let Some(ids) = anagram_index.get(&product_key(candidate)) {
+                        out.extend(ids);
+                    }
+                }
+            }
+        }
+    }
+
+    out
+}
+
 fn jaccard_similarity(set1: &HashSet<String>, set2: &HashSet<String>) -> f64 {
     let intersection = set1.intersection(set2).count();
     let union = set1.union(set2).count();
@@ -382,20 +834,224 @@ pub fn word_to_phrase_similarity(word: &str, phrase: &str) -> f64 {
     char_similarity
 }
 
-fn word_match_score(a: &str, b: &str) -> i32 {
+/// A single DFA transition's input class: either one of the pattern's own
+/// characters, or the bucket for everything else. Two characters that are
+/// both absent from the pattern can never distinguish one NFA state from
+/// another (neither ever satisfies a `pattern[i] == c` check), so they're
+/// guaranteed to drive every state to the same successor and can share one
+/// transition-table column instead of the alphabet being every char a
+/// candidate word might contain.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum CharClass {
+    Exact(char),
+    Other,
+}
+
+/// Placeholder fed through the NFA when building the `Other` column: must be
+/// a char that can't appear in `pattern`, which holds since normalized
+/// tokens are alphanumeric and this is a control character.
+const OTHER_PROBE: char = '\0';
+
+/// An NFA state set: pattern offset -> minimum errors spent to reach it,
+/// closed over epsilon-moves (pattern deletions, which consume no input).
+/// Used only during `LevAutomaton::new`'s determinization; `classify` never
+/// touches one.
+type NfaStateSet = std::collections::BTreeMap<usize, usize>;
+
+/// A Levenshtein automaton: determinizes the standard NFA (states are
+/// (pattern offset, errors spent) pairs, bounded to `max_distance` errors)
+/// into an explicit DFA once per pattern word, so classifying any number of
+/// candidate words is a simple O(len) walk of precomputed table lookups —
+/// no per-character NFA simulation or state-set allocation at match time.
+struct LevAutomaton {
+    /// `transitions[state][class]` is the successor state, or absent if that
+    /// class has no live successor (i.e. the word can't be within
+    /// `max_distance` of `pattern` on this path).
+    transitions: Vec<HashMap<CharClass, usize>>,
+    /// `accept[state]` is the minimum edit distance realized by ending the
+    /// word in that state, if the pattern is fully matched there.
+    accept: Vec<Option<usize>>,
+}
+
+impl LevAutomaton {
+    fn new(pattern: &str, max_distance: usize) -> Self {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let m = pattern.len();
+        let pattern_chars: HashSet<char> = pattern.iter().copied().collect();
+        let classes: Vec<CharClass> = pattern_chars
+            .iter()
+            .map(|&c| CharClass::Exact(c))
+            .chain(std::iter::once(CharClass::Other))
+            .collect();
+
+        let initial = epsilon_closure(&pattern, max_distance, NfaStateSet::from([(0, 0)]));
+        let mut states: Vec<NfaStateSet> = vec![initial.clone()];
+        let mut index: HashMap<NfaStateSet, usize> = HashMap::from([(initial, 0)]);
+        let mut transitions: Vec<HashMap<CharClass, usize>> = vec![HashMap::new()];
+
+        let mut frontier = 0;
+        while frontier < states.len() {
+            let current = states[frontier].clone();
+            let mut trans = HashMap::new();
+            for &class in &classes {
+                let probe = match class {
+                    CharClass::Exact(c) => c,
+                    CharClass::Other => OTHER_PROBE,
+                };
+                let next = epsilon_closure(&pattern, max_distance, step(&pattern, max_distance, &current, probe));
+                if next.is_empty() {
+                    continue;
+                }
+                let next_id = *index.entry(next.clone()).or_insert_with(|| {
+                    states.push(next);
+                    transitions.push(HashMap::new());
+                    states.len() - 1
+                });
+                trans.insert(class, next_id);
+            }
+            transitions[frontier] = trans;
+            frontier += 1;
+        }
+
+        let accept = states.iter().map(|s| s.get(&m).copied()).collect();
+
+        LevAutomaton { transitions, accept }
+    }
+
+    /// Returns the minimum edit distance from `word` to `pattern` if it's
+    /// within `max_distance`, else `None`. Pure DFA-table walk: one
+    /// char-class lookup per input character.
+    fn classify(&self, word: &str) -> Option<usize> {
+        let mut state = 0usize;
+        for c in word.chars() {
+            let exact = CharClass::Exact(c);
+            let class = if self.transitions[state].contains_key(&exact) {
+                exact
+            } else {
+                CharClass::Other
+            };
+            state = *self.transitions[state].get(&class)?;
+        }
+        self.accept[state]
+    }
+}
+
+/// Deletions from the pattern don't consume an input character, so they show
+/// up as epsilon-moves that have to be closed over both before the first
+/// character and after every subsequent transition.
+fn epsilon_closure(pattern: &[char], max_distance: usize, mut states: NfaStateSet) -> NfaStateSet {
+    let m = pattern.len();
+    loop {
+        let mut added = false;
+        for (&i, &e) in states.clone().iter() {
+            if e < max_distance && i < m {
+                let entry = states.entry(i + 1).or_insert(usize::MAX);
+                if e + 1 < *entry {
+                    *entry = e + 1;
+                    added = true;
+                }
+            }
+        }
+        if !added {
+            return states;
+        }
+    }
+}
+
+fn step(pattern: &[char], max_distance: usize, states: &NfaStateSet, c: char) -> NfaStateSet {
+    let m = pattern.len();
+    let mut next = NfaStateSet::new();
+    let mut offer = |pos: usize, err: usize, next: &mut NfaStateSet| {
+        let entry = next.entry(pos).or_insert(usize::MAX);
+        if err < *entry {
+            *entry = err;
+        }
+    };
+    for (&i, &e) in states {
+        if i < m && pattern[i] == c {
+            offer(i + 1, e, &mut next); // match, free
+        }
+        if e < max_distance {
+            if i < m {
+                offer(i + 1, e + 1, &mut next); // substitution
+            }
+            offer(i, e + 1, &mut next); // insertion (extra char in `word`)
+        }
+    }
+    next
+}
+
+/// Builds one `LevAutomaton` per unique token, so a caller that compares the
+/// same transcript against many windows (e.g. `query_internal`'s candidate
+/// loop) only pays the construction cost once per distinct word.
+fn build_automatons(tokens: &[String], max_distance: usize) -> HashMap<String, LevAutomaton> {
+    let mut automatons = HashMap::new();
+    for token in tokens {
+        automatons
+            .entry(token.clone())
+            .or_insert_with(|| LevAutomaton::new(token, max_distance));
+    }
+    automatons
+}
+
+/// Walks `matched_words` and `flags` in lockstep, accumulating char offsets
+/// as `matched_text` would be built by joining `matched_words` with single
+/// spaces, so each span points at exactly the word a UI should highlight.
+fn highlight_spans(matched_words: &[String], flags: &[WordMatchKind]) -> Vec<HighlightSpan> {
+    let mut spans = Vec::with_capacity(flags.len());
+    let mut cursor = 0usize;
+    for (word, kind) in matched_words.iter().zip(flags) {
+        let char_start = cursor;
+        let char_end = char_start + word.chars().count();
+        spans.push(HighlightSpan {
+            char_start,
+            char_end,
+            kind: kind.clone(),
+        });
+        cursor = char_end + 1; // +1 for the joining space
+    }
+    spans
+}
+
+fn word_match_score(
+    a: &str,
+    b: &str,
+    automatons: &HashMap<String, LevAutomaton>,
+    synonyms: &HashMap<String, HashSet<String>>,
+) -> i32 {
     if a == b {
-        SCORE_EXACT
-    } else if levenshtein_distance(a, b) <= 1 {
+        return SCORE_EXACT;
+    }
+    if synonyms.get(a).is_some_and(|group| group.contains(b)) {
+        return SCORE_EXACT;
+    }
+    let within_one = automatons
+        .get(a)
+        .and_then(|dfa| dfa.classify(b))
+        .map(|distance| distance <= 1)
+        .unwrap_or_else(|| levenshtein_distance(a, b) <= 1);
+    if within_one {
         SCORE_FUZZY
     } else {
         SCORE_MISMATCH
     }
 }
 
+/// Local alignment via the Gotoh affine-gap formulation: `m_mat` scores an
+/// aligned (diagonal) step, `ix` a run of transcript tokens with no matching
+/// window token, `iy` a run of window tokens with no matching transcript
+/// token. Each gap matrix pays `gap_open` to start a run and only
+/// `gap_extend` per additional token, so one long skip costs far less than
+/// the same number of words lost to scattered single-word gaps would under
+/// a flat per-word penalty.
 fn smith_waterman_align(
     transcript_tokens: &[String],
     window_tokens: &[String],
     best_score_found: i32,
+    automatons: &HashMap<String, LevAutomaton>,
+    synonyms: &HashMap<String, HashSet<String>>,
+    gap_open: i32,
+    gap_extend: i32,
 ) -> AlignmentResult {
     let m = transcript_tokens.len();
     let n = window_tokens.len();
@@ -404,45 +1060,95 @@ fn smith_waterman_align(
             alignment_score: 0,
             start_index_in_window: 0,
             end_index_in_window: 0,
+            word_flags: Vec::new(),
         };
     }
 
-    let mut dp = vec![vec![0i32; n + 1]; m + 1];
-    let mut trace = vec![vec![0u8; n + 1]; m + 1];
+    // Sentinel for "this gap matrix cell is unreachable", kept far enough
+    // from i32::MIN that repeatedly subtracting gap_extend from it can't
+    // overflow.
+    const NEG_INF: i32 = i32::MIN / 2;
+
+    let mut m_mat = vec![vec![0i32; n + 1]; m + 1];
+    let mut ix = vec![vec![NEG_INF; n + 1]; m + 1];
+    let mut iy = vec![vec![NEG_INF; n + 1]; m + 1];
+    // trace_m: 0 = local-alignment start, 1/2/3 = diagonal from M/Ix/Iy.
+    let mut trace_m = vec![vec![0u8; n + 1]; m + 1];
+    // trace_ix/trace_iy: 1 = opened from M, 2 = extended from the same matrix.
+    let mut trace_ix = vec![vec![0u8; n + 1]; m + 1];
+    let mut trace_iy = vec![vec![0u8; n + 1]; m + 1];
+
     let mut max_score = 0i32;
     let mut max_pos = (0usize, 0usize);
+    let mut max_matrix = 0u8;
 
     for i in 1..=m {
         let mut row_max = 0i32;
         for j in 1..=n {
-            let match_score = word_match_score(&transcript_tokens[i - 1], &window_tokens[j - 1]);
-            let diag = dp[i - 1][j - 1] + match_score;
-            let up = dp[i - 1][j] + SCORE_GAP;
-            let left = dp[i][j - 1] + SCORE_GAP;
+            let ix_open = m_mat[i - 1][j].saturating_sub(gap_open);
+            let ix_extend = ix[i - 1][j].saturating_sub(gap_extend);
+            if ix_extend > ix_open {
+                ix[i][j] = ix_extend;
+                trace_ix[i][j] = 2;
+            } else {
+                ix[i][j] = ix_open;
+                trace_ix[i][j] = 1;
+            }
 
-            let mut best = 0i32;
+            let iy_open = m_mat[i][j - 1].saturating_sub(gap_open);
+            let iy_extend = iy[i][j - 1].saturating_sub(gap_extend);
+            if iy_extend > iy_open {
+                iy[i][j] = iy_extend;
+                trace_iy[i][j] = 2;
+            } else {
+                iy[i][j] = iy_open;
+                trace_iy[i][j] = 1;
+            }
+
+            let match_score = word_match_score(
+                &transcript_tokens[i - 1],
+                &window_tokens[j - 1],
+                automatons,
+                synonyms,
+            );
+
+            let mut best_diag = 0i32;
             let mut dir = 0u8;
-            if diag > best {
-                best = diag;
+            if m_mat[i - 1][j - 1] > best_diag {
+                best_diag = m_mat[i - 1][j - 1];
                 dir = 1;
             }
-            if up > best {
-                best = up;
+            if ix[i - 1][j - 1] > best_diag {
+                best_diag = ix[i - 1][j - 1];
                 dir = 2;
             }
-            if left > best {
-                best = left;
+            if iy[i - 1][j - 1] > best_diag {
+                best_diag = iy[i - 1][j - 1];
                 dir = 3;
             }
 
-            dp[i][j] = best;
-            trace[i][j] = if best > 0 { dir } else { 0 };
+            let cell = best_diag + match_score;
+            if cell > 0 {
+                m_mat[i][j] = cell;
+                trace_m[i][j] = dir;
+            } else {
+                m_mat[i][j] = 0;
+                trace_m[i][j] = 0;
+            }
 
-            if best > max_score {
-                max_score = best;
+            let cell_best = m_mat[i][j].max(ix[i][j]).max(iy[i][j]);
+            if cell_best > max_score {
+                max_score = cell_best;
                 max_pos = (i, j);
+                max_matrix = if cell_best == m_mat[i][j] {
+                    0
+                } else if cell_best == ix[i][j] {
+                    1
+                } else {
+                    2
+                };
             }
-            row_max = row_max.max(best);
+            row_max = row_max.max(cell_best);
         }
 
         let remaining = (m - i) as i32;
@@ -457,37 +1163,96 @@ fn smith_waterman_align(
             alignment_score: 0,
             start_index_in_window: 0,
             end_index_in_window: 0,
+            word_flags: Vec::new(),
         };
     }
 
     let (mut i, mut j) = max_pos;
     let end_j = j.saturating_sub(1);
+    let mut matrix = max_matrix;
+    // Collected back-to-front (from the alignment's end to its start), one
+    // entry per window token consumed; reversed into left-to-right order
+    // below. A transcript-only gap (Ix) consumes no window token, so it adds
+    // nothing here.
+    let mut word_flags_rev: Vec<WordMatchKind> = Vec::new();
     while i > 0 && j > 0 {
-        match trace[i][j] {
-            0 => break,
-            1 => {
-                i -= 1;
-                j -= 1;
-            }
-            2 => {
-                i -= 1;
-            }
-            3 => {
-                j -= 1;
-            }
-            _ => break,
+        match matrix {
+            0 => match trace_m[i][j] {
+                // A zero-scoring cell lies outside the alignment.
+                0 if m_mat[i][j] <= 0 => break,
+                dir @ (0 | 1 | 2 | 3) => {
+                    let transcript_word = &transcript_tokens[i - 1];
+                    let window_word = &window_tokens[j - 1];
+                    let kind = if transcript_word == window_word
+                        || synonyms
+                            .get(transcript_word)
+                            .is_some_and(|group| group.contains(window_word))
+                    {
+                        WordMatchKind::Exact
+                    } else {
+                        WordMatchKind::Fuzzy
+                    };
+                    word_flags_rev.push(kind);
+                    i -= 1;
+                    j -= 1;
+                    matrix = match dir {
+                        // The local-alignment start cell is recorded above, then traceback ends.
+                        0 => break,
+                        1 => 0,
+                        2 => 1,
+                        _ => 2,
+                    };
+                }
+                _ => break,
+            },
+            1 => match trace_ix[i][j] {
+                1 => {
+                    i -= 1;
+                    matrix = 0;
+                }
+                2 => {
+                    i -= 1;
+                    matrix = 1;
+                }
+                _ => break,
+            },
+            _ => match trace_iy[i][j] {
+                1 => {
+                    word_flags_rev.push(WordMatchKind::Gap);
+                    j -= 1;
+                    matrix = 0;
+                }
+                2 => {
+                    word_flags_rev.push(WordMatchKind::Gap);
+                    j -= 1;
+                    matrix = 2;
+                }
+                _ => break,
+            },
         }
     }
+    word_flags_rev.reverse();
 
     AlignmentResult {
         alignment_score: max_score,
         start_index_in_window: j,
         end_index_in_window: end_j,
+        word_flags: word_flags_rev,
     }
 }
 
 fn sequence_alignment_similarity(transcript_words: &[String], chunk_words: &[String]) -> f64 {
-    let alignment = smith_waterman_align(transcript_words, chunk_words, 0);
+    let automatons = build_automatons(transcript_words, 1);
+    let no_synonyms = HashMap::new();
+    let alignment = smith_waterman_align(
+        transcript_words,
+        chunk_words,
+        0,
+        &automatons,
+        &no_synonyms,
+        DEFAULT_GAP_OPEN,
+        DEFAULT_GAP_EXTEND,
+    );
     if transcript_words.is_empty() {
         return 0.0;
     }
@@ -584,6 +1349,126 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lev_automaton_classifies_by_edit_distance() {
+        let dfa = LevAutomaton::new("brown", 2);
+        assert_eq!(dfa.classify("brown"), Some(0), "exact match is distance 0");
+        assert_eq!(dfa.classify("brawn"), Some(1), "one substitution is distance 1");
+        assert_eq!(dfa.classify("brownn"), Some(1), "one trailing insertion is distance 1");
+        assert_eq!(dfa.classify("brn"), Some(2), "two deletions (o, w) is distance 2");
+        assert_eq!(dfa.classify("giraffe"), None, "outside max_distance has no accepting state");
+    }
+
+    #[test]
+    fn gotoh_affine_gap_favors_one_contiguous_skip_over_scattered_ones() {
+        // Same three extra window words either swallowed by one gap run or
+        // split into three single-word runs; affine scoring should charge
+        // `gap_open` once (plus two `gap_extend`s) for the former but three
+        // separate `gap_open`s for the latter.
+        let transcript: Vec<String> = ["a", "b", "c", "d"].iter().map(|s| s.to_string()).collect();
+        let contiguous: Vec<String> = ["a", "b", "x", "y", "z", "c", "d"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let scattered: Vec<String> = ["a", "x", "b", "y", "c", "z", "d"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let automatons = build_automatons(&transcript, 1);
+        let synonyms = HashMap::new();
+
+        let contiguous_result = smith_waterman_align(
+            &transcript,
+            &contiguous,
+            0,
+            &automatons,
+            &synonyms,
+            DEFAULT_GAP_OPEN,
+            DEFAULT_GAP_EXTEND,
+        );
+        let scattered_result = smith_waterman_align(
+            &transcript,
+            &scattered,
+            0,
+            &automatons,
+            &synonyms,
+            DEFAULT_GAP_OPEN,
+            DEFAULT_GAP_EXTEND,
+        );
+
+        assert_eq!(contiguous_result.alignment_score, 4);
+        assert_eq!(scattered_result.alignment_score, 2);
+        assert!(
+            contiguous_result.alignment_score > scattered_result.alignment_score,
+            "one gap-open plus extensions should cost less than paying gap-open three separate times"
+        );
+    }
+
+    #[test]
+    fn highlight_spans_report_char_ranges_and_kinds() {
+        let words = vec!["the".to_string(), "quick".to_string(), "fox".to_string()];
+        let flags = vec![WordMatchKind::Exact, WordMatchKind::Gap, WordMatchKind::Fuzzy];
+        let spans = highlight_spans(&words, &flags);
+
+        assert_eq!(spans.len(), 3);
+        assert_eq!((spans[0].char_start, spans[0].char_end), (0, 3), "\"the\"");
+        assert_eq!((spans[1].char_start, spans[1].char_end), (4, 9), "\"quick\", offset by the joining space");
+        assert_eq!((spans[2].char_start, spans[2].char_end), (10, 13), "\"fox\"");
+        assert!(matches!(spans[0].kind, WordMatchKind::Exact));
+        assert!(matches!(spans[1].kind, WordMatchKind::Gap));
+        assert!(matches!(spans[2].kind, WordMatchKind::Fuzzy));
+    }
+
+    #[test]
+    fn synonyms_allow_a_paraphrased_transcript_to_match() {
+        let mut locator = make_locator("the driver parked the car outside");
+        locator.add_synonyms("automobile", vec!["car".to_string(), "vehicle".to_string()]);
+
+        let value = locator.query_internal("the driver parked the automobile outside");
+        let result = value.expect("synonym-expanded n-grams should still find the window");
+        assert!(
+            result.confidence > 0.9,
+            "a synonym should score as an exact match, got confidence {}",
+            result.confidence
+        );
+    }
+
+    #[test]
+    fn multilingual_normalization_folds_diacritics_and_segments_cjk() {
+        let mut locator = TextLocator::new();
+        locator.set_multilingual_normalization(true);
+        locator.preprocess("café 你好世界 résumé");
+
+        assert_eq!(
+            locator.state.words,
+            vec!["cafe", "你", "好", "世", "界", "resume"],
+            "diacritics fold to base letters and each CJK glyph becomes its own token"
+        );
+
+        let value = locator.query_internal("cafe 你好 resume");
+        assert!(value.is_some(), "a query normalized the same way should still align");
+    }
+
+    #[test]
+    fn query_top_k_does_not_prune_against_a_partial_minimum() {
+        // Three disjoint 4-word windows, decreasing in relevance to the
+        // query: window 0 matches it exactly (score 8), window 1 matches its
+        // last 3 words but not its first (score 6), window 2 shares only
+        // "gamma". Pruning window 1 against the one-entry heap's minimum (8)
+        // would stop its alignment after the first row, since
+        // 0 + 3 * SCORE_EXACT < 8, so it would score 0 and drop out, leaving
+        // a single result instead of the two strongest windows.
+        let mut locator = TextLocator::new();
+        locator.state.window_size_words = 4;
+        locator.state.step_size_words = 4;
+        locator.preprocess("alpha beta gamma delta zulu beta gamma delta gamma echo yankee xray");
+
+        let results = locator.query_top_k_internal("alpha beta gamma delta", 2);
+        assert_eq!(results.len(), 2);
+        let window_ids: Vec<usize> = results.iter().map(|r| r.window_id).collect();
+        assert_eq!(window_ids, vec![0, 1], "expected the two strongest windows, in score order");
+    }
+
     #[test]
     fn serde_roundtrip() {
         let mut locator = TextLocator::new();